@@ -0,0 +1,325 @@
+use crate::helpers;
+use crate::Currencies;
+use crate::types::Currency;
+use std::collections::{HashMap, HashSet};
+
+/// Combines listings with identical prices into a single listing, summing their quantities.
+/// Prices are grouped by exact equality, meaning `1 key` and `0 keys, 60 ref` are not combined
+/// even if they are worth the same. The order in which each price first appears is preserved.
+/// 
+/// Quantities are [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, combine_listings, refined};
+/// 
+/// let a = Currencies { keys: 1, weapons: 0 };
+/// let b = Currencies { keys: 0, weapons: refined!(5) };
+/// let combined = combine_listings(&[(a, 2), (b, 1), (a, 3)]);
+/// 
+/// assert_eq!(combined, vec![(a, 5), (b, 1)]);
+/// ```
+pub fn combine_listings(listings: &[(Currencies, u32)]) -> Vec<(Currencies, u32)> {
+    let mut combined: Vec<(Currencies, u32)> = Vec::new();
+    let mut indices: HashMap<Currencies, usize> = HashMap::new();
+    
+    for (currencies, quantity) in listings {
+        if let Some(&index) = indices.get(currencies) {
+            let total = &mut combined[index].1;
+            
+            *total = total.saturating_add(*quantity);
+        } else {
+            indices.insert(*currencies, combined.len());
+            combined.push((*currencies, *quantity));
+        }
+    }
+    
+    combined
+}
+
+/// Gets the currencies with the lowest value using the given key price (represented as weapons).
+/// If several currencies are equally the lowest, the first is returned. `None` if the iterator is
+/// empty.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, min_by_value_iter, refined};
+/// 
+/// let key_price_weapons = refined!(50);
+/// let prices = vec![
+///     Currencies { keys: 1, weapons: 0 },
+///     Currencies { keys: 0, weapons: refined!(40) },
+///     Currencies { keys: 0, weapons: refined!(60) },
+/// ];
+/// 
+/// assert_eq!(
+///     min_by_value_iter(prices, key_price_weapons),
+///     Some(Currencies { keys: 0, weapons: refined!(40) }),
+/// );
+/// ```
+pub fn min_by_value_iter<I>(iter: I, key_price_weapons: Currency) -> Option<Currencies>
+where
+    I: IntoIterator<Item = Currencies>,
+{
+    iter.into_iter().min_by_key(|currencies| currencies.to_weapons(key_price_weapons))
+}
+
+/// Gets the currencies with the highest value using the given key price (represented as
+/// weapons). If several currencies are equally the highest, the last is returned. `None` if the
+/// iterator is empty.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, max_by_value_iter, refined};
+/// 
+/// let key_price_weapons = refined!(50);
+/// let prices = vec![
+///     Currencies { keys: 1, weapons: 0 },
+///     Currencies { keys: 0, weapons: refined!(40) },
+///     Currencies { keys: 0, weapons: refined!(60) },
+/// ];
+/// 
+/// assert_eq!(
+///     max_by_value_iter(prices, key_price_weapons),
+///     Some(Currencies { keys: 0, weapons: refined!(60) }),
+/// );
+/// ```
+pub fn max_by_value_iter<I>(iter: I, key_price_weapons: Currency) -> Option<Currencies>
+where
+    I: IntoIterator<Item = Currencies>,
+{
+    iter.into_iter().max_by_key(|currencies| currencies.to_weapons(key_price_weapons))
+}
+
+/// Gets the arithmetic mean of the value of the currencies using the given key price
+/// (represented as weapons), rounded to the nearest weapon. The total is computed as an `i128`
+/// so summing many large values will not overflow. `None` if the iterator is empty.
+/// 
+/// This function is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, mean, refined};
+/// 
+/// let key_price_weapons = refined!(50);
+/// let prices = vec![
+///     Currencies { keys: 1, weapons: 0 },
+///     Currencies { keys: 0, weapons: refined!(40) },
+///     Currencies { keys: 0, weapons: refined!(30) },
+/// ];
+/// 
+/// assert_eq!(
+///     mean(prices, key_price_weapons),
+///     Some(Currencies { keys: 0, weapons: refined!(40) }),
+/// );
+/// ```
+pub fn mean<I>(iter: I, key_price_weapons: Currency) -> Option<Currencies>
+where
+    I: IntoIterator<Item = Currencies>,
+{
+    let (total, count) = iter
+        .into_iter()
+        .fold((0i128, 0i128), |(total, count), currencies| {
+            let weapons = helpers::to_weapons_i128(
+                currencies.keys,
+                currencies.weapons,
+                key_price_weapons,
+            );
+            
+            (total + weapons, count + 1)
+        });
+    
+    if count == 0 {
+        return None;
+    }
+    
+    let weapons = helpers::round_div_i128(total, count);
+    
+    Some(Currencies::from_weapons(
+        helpers::saturating_i128_to_currency(weapons),
+        key_price_weapons,
+    ))
+}
+
+/// Removes currencies which are equal in value to currencies appearing earlier using the given
+/// key price (represented as weapons), preserving order. Unlike [`combine_listings`], which
+/// groups prices by exact equality, `1 key` and `0 keys, 50 ref` are considered duplicates at a
+/// key price of 50 refined.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, dedup_by_value, refined};
+/// 
+/// let key_price_weapons = refined!(50);
+/// let a = Currencies { keys: 1, weapons: 0 };
+/// let b = Currencies { keys: 0, weapons: refined!(50) };
+/// let c = Currencies { keys: 0, weapons: refined!(5) };
+/// 
+/// assert_eq!(dedup_by_value(&[a, b, c], key_price_weapons), vec![a, c]);
+/// ```
+pub fn dedup_by_value(items: &[Currencies], key_price_weapons: Currency) -> Vec<Currencies> {
+    let mut seen: HashSet<Currency> = HashSet::new();
+    
+    items
+        .iter()
+        .filter(|currencies| seen.insert(currencies.to_weapons(key_price_weapons)))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn combines_listings() {
+        let a = Currencies {
+            keys: 1,
+            weapons: refined!(5),
+        };
+        let b = Currencies {
+            keys: 0,
+            weapons: refined!(23) + scrap!(4),
+        };
+        let c = Currencies {
+            keys: 2,
+            weapons: 0,
+        };
+        
+        assert_eq!(
+            combine_listings(&[(a, 2), (b, 1), (a, 3), (c, 4), (b, 6)]),
+            vec![(a, 5), (b, 7), (c, 4)],
+        );
+    }
+    
+    #[test]
+    fn combines_listings_saturating() {
+        let a = Currencies {
+            keys: 1,
+            weapons: 0,
+        };
+        
+        assert_eq!(combine_listings(&[(a, u32::MAX), (a, 1)]), vec![(a, u32::MAX)]);
+    }
+    
+    #[test]
+    fn gets_min_and_max_by_value() {
+        let key_price_weapons = refined!(50);
+        let prices = [
+            Currencies {
+                keys: 1,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 0,
+                weapons: refined!(45),
+            },
+            Currencies {
+                keys: 2,
+                weapons: -refined!(10),
+            },
+            Currencies {
+                keys: 0,
+                weapons: refined!(70),
+            },
+        ];
+        
+        assert_eq!(
+            min_by_value_iter(prices, key_price_weapons),
+            Some(Currencies {
+                keys: 0,
+                weapons: refined!(45),
+            }),
+        );
+        assert_eq!(
+            max_by_value_iter(prices, key_price_weapons),
+            Some(Currencies {
+                keys: 2,
+                weapons: -refined!(10),
+            }),
+        );
+    }
+    
+    #[test]
+    fn gets_min_and_max_by_value_empty() {
+        assert_eq!(min_by_value_iter(Vec::new(), refined!(50)), None);
+        assert_eq!(max_by_value_iter(Vec::new(), refined!(50)), None);
+    }
+    
+    #[test]
+    fn mean_of_values() {
+        let key_price_weapons = refined!(50);
+        let prices = [
+            Currencies {
+                keys: 1,
+                weapons: refined!(2),
+            },
+            Currencies {
+                keys: 0,
+                weapons: refined!(50) + scrap!(1),
+            },
+            Currencies {
+                keys: 2,
+                weapons: -refined!(5),
+            },
+        ];
+        
+        // (936 + 902 + 1710) / 3 = 1182.67 weapons, rounded to 1183
+        assert_eq!(
+            mean(prices, key_price_weapons),
+            Some(Currencies {
+                keys: 1,
+                weapons: refined!(15) + scrap!(6) + 1,
+            }),
+        );
+    }
+    
+    #[test]
+    fn mean_does_not_overflow() {
+        let key_price_weapons = refined!(50);
+        let prices = [
+            Currencies {
+                keys: 0,
+                weapons: Currency::MAX,
+            },
+            Currencies {
+                keys: 0,
+                weapons: Currency::MAX,
+            },
+        ];
+        
+        assert_eq!(
+            mean(prices, key_price_weapons),
+            Some(Currencies::from_weapons(Currency::MAX, key_price_weapons)),
+        );
+    }
+    
+    #[test]
+    fn mean_empty() {
+        assert_eq!(mean(Vec::new(), refined!(50)), None);
+    }
+    
+    #[test]
+    fn dedups_by_value() {
+        let key_price_weapons = refined!(50);
+        let a = Currencies {
+            keys: 1,
+            weapons: refined!(5),
+        };
+        let b = Currencies {
+            keys: 0,
+            weapons: refined!(23) + scrap!(4),
+        };
+        let c = Currencies {
+            keys: 0,
+            weapons: refined!(55),
+        };
+        let d = Currencies {
+            keys: 2,
+            weapons: -refined!(45),
+        };
+        
+        assert_eq!(dedup_by_value(&[a, b, c, d, b], key_price_weapons), vec![a, b]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use crate::types::Currency;
+use crate::constants::{ONE_REF, ONE_REC, ONE_SCRAP};
+
+/// A metal value (represented as weapons) which is always a multiple of `STEP` weapons. For
+/// example, [`RefinedMetal`] can only hold whole refined values. Arithmetic preserves alignment.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{AlignedMetal, ONE_REF, refined, scrap};
+/// 
+/// let metal = AlignedMetal::<ONE_REF>::new(refined!(5)).unwrap();
+/// 
+/// assert_eq!(metal.weapons(), refined!(5));
+/// // Not a whole refined value.
+/// assert!(AlignedMetal::<ONE_REF>::new(refined!(5) + scrap!(1)).is_none());
+/// ```
+#[derive(Debug, Default, Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct AlignedMetal<const STEP: Currency>(Currency);
+
+/// Metal aligned to whole refined.
+pub type RefinedMetal = AlignedMetal<ONE_REF>;
+/// Metal aligned to whole reclaimed.
+pub type ReclaimedMetal = AlignedMetal<ONE_REC>;
+/// Metal aligned to whole scrap.
+pub type ScrapMetal = AlignedMetal<ONE_SCRAP>;
+
+impl<const STEP: Currency> AlignedMetal<STEP> {
+    /// Creates a new [`AlignedMetal`] from a value in weapons. `None` if the value is not a
+    /// multiple of `STEP`, or if `STEP` is `0`.
+    pub fn new(weapons: Currency) -> Option<Self> {
+        if weapons.checked_rem(STEP)? != 0 {
+            return None;
+        }
+        
+        Some(Self(weapons))
+    }
+    
+    /// Creates a new [`AlignedMetal`] from a number of steps e.g. `RefinedMetal::from_steps(5)`
+    /// is 5 refined. `None` if the result overflows, or if `STEP` is `0`.
+    pub fn from_steps(steps: Currency) -> Option<Self> {
+        if STEP == 0 {
+            return None;
+        }
+        
+        Some(Self(steps.checked_mul(STEP)?))
+    }
+    
+    /// Gets the value in weapons.
+    pub fn weapons(&self) -> Currency {
+        self.0
+    }
+    
+    /// Gets the number of steps in the value e.g. the number of refined for [`RefinedMetal`].
+    /// Always `0` if `STEP` is `0`.
+    pub fn steps(&self) -> Currency {
+        // The only value with a step of 0 is the default value of 0.
+        self.0.checked_div(STEP).unwrap_or_default()
+    }
+    
+    /// Adds two aligned values. `None` if the result overflows integer bounds.
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Some(Self(self.0.checked_add(other.0)?))
+    }
+    
+    /// Subtracts two aligned values. `None` if the result overflows integer bounds.
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        Some(Self(self.0.checked_sub(other.0)?))
+    }
+    
+    /// Multiplies an aligned value. `None` if the result overflows integer bounds.
+    pub fn checked_mul(&self, rhs: Currency) -> Option<Self> {
+        Some(Self(self.0.checked_mul(rhs)?))
+    }
+}
+
+impl<const STEP: Currency> From<AlignedMetal<STEP>> for Currency {
+    fn from(metal: AlignedMetal<STEP>) -> Self {
+        metal.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn creates_refined_aligned() {
+        let metal = RefinedMetal::new(refined!(23));
+        
+        assert_eq!(metal.map(|metal| metal.steps()), Some(23));
+    }
+    
+    #[test]
+    fn rejects_half_scrap() {
+        assert!(ScrapMetal::new(scrap!(3) + 1).is_none());
+    }
+    
+    #[test]
+    fn rejects_zero_step() {
+        assert!(AlignedMetal::<0>::new(0).is_none());
+    }
+    
+    #[test]
+    fn rejects_zero_step_from_steps() {
+        assert!(AlignedMetal::<0>::from_steps(0).is_none());
+        assert!(AlignedMetal::<0>::from_steps(5).is_none());
+        assert_eq!(AlignedMetal::<0>::default().steps(), 0);
+    }
+    
+    #[test]
+    fn arithmetic_preserves_alignment() {
+        let a = RefinedMetal::from_steps(2).unwrap();
+        let b = RefinedMetal::from_steps(3).unwrap();
+        
+        assert_eq!(a.checked_add(b).unwrap().weapons(), refined!(5));
+        assert_eq!(a.checked_sub(b).unwrap().weapons(), -refined!(1));
+        assert_eq!(a.checked_mul(4).unwrap().steps(), 8);
+        assert!(a.checked_mul(Currency::MAX).is_none());
+    }
+    
+    #[test]
+    fn converts_into_currency() {
+        let weapons: Currency = ScrapMetal::new(scrap!(4)).unwrap().into();
+        
+        assert_eq!(weapons, scrap!(4));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use crate::types::Currency;
+
+/// The components of [`Currencies`](crate::Currencies), computed once. See
+/// [`Currencies::components`](crate::Currencies::components).
+/// 
+/// The metal counts are the fewest items needed to make up `weapons`. Negative values produce
+/// negative counts.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Components {
+    /// Amount of keys.
+    pub keys: Currency,
+    /// Amount of metal expressed as weapons.
+    pub weapons: Currency,
+    /// Amount of metal expressed as a float e.g. `1.33` ref.
+    pub refined_float: f32,
+    /// Number of whole refined.
+    pub refined: Currency,
+    /// Number of whole reclaimed after taking out refined.
+    pub reclaimed: Currency,
+    /// Number of whole scrap after taking out refined and reclaimed.
+    pub scrap: Currency,
+    /// Number of weapons left over after taking out refined, reclaimed, and scrap.
+    pub weapon: Currency,
+}
@@ -15,5 +15,15 @@ pub const ONE_REF_FLOAT: f32 = ONE_REF as f32;
 pub const KEY_SYMBOL: &str = "key";
 /// Symbol for multiple keys.
 pub const KEYS_SYMBOL: &str = "keys";
+/// Abbreviated symbol for keys.
+pub const KEYS_ABBREVIATED_SYMBOL: &str = "k";
 /// Symbol for metal.
-pub const METAL_SYMBOL: &str = "ref";
\ No newline at end of file
+pub const METAL_SYMBOL: &str = "ref";
+/// Symbol for one weapon.
+pub const WEAPON_SYMBOL: &str = "weapon";
+/// Symbol for multiple weapons.
+pub const WEAPONS_SYMBOL: &str = "weapons";
+/// Keyword for a free price.
+pub const FREE_KEYWORD: &str = "free";
+/// Keyword for a price open to offers.
+pub const OFFER_KEYWORD: &str = "offer";
\ No newline at end of file
@@ -1,8 +1,28 @@
 use crate::helpers;
 use crate::types::Currency;
-use crate::error::{ParseError, TryFromFloatCurrenciesError};
-use crate::constants::{KEYS_SYMBOL, KEY_SYMBOL, METAL_SYMBOL};
-use crate::{FloatCurrencies, Rounding};
+use crate::error::{ParseError, PriceError, TryFromFloatCurrenciesError};
+use crate::constants::{
+    KEYS_SYMBOL,
+    KEYS_ABBREVIATED_SYMBOL,
+    KEY_SYMBOL,
+    METAL_SYMBOL,
+    FREE_KEYWORD,
+    OFFER_KEYWORD,
+    ONE_REF,
+    ONE_WEAPON,
+};
+use crate::{
+    Components,
+    Denomination,
+    DisplayOptions,
+    FloatCurrencies,
+    KeyPrice,
+    MetalBreakdown,
+    OverflowPolicy,
+    ParseOptions,
+    ParsedPrice,
+    Rounding,
+};
 use std::fmt;
 use std::cmp::{Ord, Ordering};
 use auto_ops::impl_op_ex;
@@ -46,6 +66,12 @@ impl Ord for Currencies {
 }
 
 impl Currencies {
+    /// The smallest tradeable price, one weapon (half a scrap).
+    pub const MIN_TRADEABLE: Self = Self {
+        keys: 0,
+        weapons: ONE_WEAPON,
+    };
+    
     /// Creates a new [`Currencies`] with `0` keys and `0` weapons. Same as `Currencies::default()`.
     /// 
     /// # Examples
@@ -63,6 +89,11 @@ impl Currencies {
     /// 
     /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
     /// 
+    /// # Panics
+    /// Panics if `key_price_weapons` is zero. In debug builds this is caught by an assertion with
+    /// a descriptive message. Use [`Currencies::checked_from_weapons`] if the key price may be
+    /// zero.
+    /// 
     /// # Examples
     /// ```
     /// use tf2_price::{Currencies, refined};
@@ -76,6 +107,8 @@ impl Currencies {
         weapons: Currency,
         key_price_weapons: Currency,
     ) -> Self {
+        debug_assert!(key_price_weapons != 0, "key price must not be zero");
+        
         Self {
             // Will be 0 if weapons is 30 and key price is 32 (rounds down)
             keys: weapons.saturating_div(key_price_weapons),
@@ -86,16 +119,19 @@ impl Currencies {
     /// Converts a weapon value into the appropriate number of keys and weapons using the given 
     /// key price (represented as weapons).
     /// 
-    /// Checks for safe conversion.
+    /// Checks for safe conversion. `None` if `key_price_weapons` is zero, since the number of keys
+    /// is undefined, or if the division overflows e.g. [`Currency::MIN`] divided by `-1`.
+    /// Otherwise, the result is the same as [`Currencies::from_weapons`].
     /// 
     /// # Examples
     /// ```
     /// use tf2_price::{Currencies, refined};
     /// 
     /// let key_price = refined!(60);
-    /// let currencies = Currencies::from_weapons(refined!(80), key_price);
+    /// let currencies = Currencies::checked_from_weapons(refined!(80), key_price);
     /// 
-    /// assert_eq!(currencies, Currencies { keys: 1, weapons: refined!(20) });
+    /// assert_eq!(currencies, Some(Currencies { keys: 1, weapons: refined!(20) }));
+    /// assert_eq!(Currencies::checked_from_weapons(refined!(80), 0), None);
     /// ```
     pub fn checked_from_weapons(
         weapons: Currency,
@@ -110,6 +146,69 @@ impl Currencies {
         })
     }
     
+    /// Converts a weapon value into the appropriate number of keys and weapons using the given
+    /// key price (represented as weapons), along with the sign of the weapon value (`-1`, `0` or
+    /// `1`). This is useful for displaying the sign of the whole value separately from its
+    /// components.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price = refined!(60);
+    /// let (currencies, sign) = Currencies::from_weapons_signed(-refined!(80), key_price);
+    /// 
+    /// assert_eq!(currencies, Currencies { keys: -1, weapons: -refined!(20) });
+    /// assert_eq!(sign, -1);
+    /// ```
+    pub fn from_weapons_signed(
+        weapons: Currency,
+        key_price_weapons: Currency,
+    ) -> (Self, i32) {
+        let sign = match weapons.cmp(&0) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+        
+        (Self::from_weapons(weapons, key_price_weapons), sign)
+    }
+    
+    /// Creates currencies from a stored `(keys, weapons)` pair, validating that the pair is
+    /// neatened using the given key price (represented as weapons). This is useful for catching
+    /// corrupt stored data early.
+    /// 
+    /// # Errors
+    /// Errors if the magnitude of `weapons` is not less than `key_price_weapons`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// 
+    /// assert!(Currencies::from_weapons_pair_validated((1, refined!(10)), key_price).is_ok());
+    /// assert!(Currencies::from_weapons_pair_validated((1, refined!(60)), key_price).is_err());
+    /// ```
+    pub fn from_weapons_pair_validated(
+        (keys, weapons): (Currency, Currency),
+        key_price_weapons: Currency,
+    ) -> Result<Self, PriceError> {
+        if weapons.saturating_abs() >= key_price_weapons {
+            return Err(PriceError::NotNeatened {
+                weapons,
+                key_price_weapons,
+            });
+        }
+        
+        Ok(Self {
+            keys,
+            weapons,
+        })
+    }
+    
     /// Converts from [`FloatCurrencies`] using the given key price (represented as weapons).
     /// 
     /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
@@ -218,6 +317,81 @@ impl Currencies {
         }
     }
     
+    /// Converts an f32 refined value into `Currencies` with no keys. The value is rounded to the
+    /// nearest weapon.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let currencies = Currencies::from_metal_f32(23.44);
+    /// 
+    /// assert_eq!(currencies, Currencies { keys: 0, weapons: refined!(23) + scrap!(4) });
+    /// ```
+    pub fn from_metal_f32(metal: f32) -> Self {
+        Self {
+            keys: 0,
+            weapons: helpers::get_weapons_from_metal_float(metal),
+        }
+    }
+    
+    /// Converts an f32 refined value into `Currencies` with no keys. The value is rounded to the
+    /// nearest weapon. `None` if the value is NaN, infinite, or out of bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// assert_eq!(
+    ///     Currencies::checked_from_metal_f32(23.44),
+    ///     Some(Currencies { keys: 0, weapons: refined!(23) + scrap!(4) }),
+    /// );
+    /// assert_eq!(Currencies::checked_from_metal_f32(f32::NAN), None);
+    /// ```
+    pub fn checked_from_metal_f32(metal: f32) -> Option<Self> {
+        Some(Self {
+            keys: 0,
+            weapons: helpers::checked_get_weapons_from_metal_float(metal)?,
+        })
+    }
+    
+    /// Converts float key and refined values into [`Currencies`] using the given key price
+    /// (represented as weapons). Any fractional key value is converted to weapons. This will
+    /// always produce a value: values beyond the bounds of [`Currency`] are clamped and NaN
+    /// values are treated as zero.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let key_price_weapons = refined!(60);
+    /// let currencies = Currencies::from_floats_saturating(1.5, 23.44, key_price_weapons);
+    /// 
+    /// assert_eq!(currencies, Currencies { keys: 1, weapons: refined!(53) + scrap!(4) });
+    /// 
+    /// let currencies = Currencies::from_floats_saturating(f32::NAN, 2.0, key_price_weapons);
+    /// 
+    /// assert_eq!(currencies, Currencies { keys: 0, weapons: refined!(2) });
+    /// ```
+    pub fn from_floats_saturating(
+        keys: f32,
+        refined: f32,
+        key_price_weapons: Currency,
+    ) -> Self {
+        // Float to integer casts saturate at the integer bounds and convert NaN to 0.
+        Self::from_float_currencies_with(
+            FloatCurrencies {
+                keys,
+                metal: refined,
+            },
+            key_price_weapons,
+        )
+    }
+    
     /// Converts currencies to a weapon value using the given key price (represented as weapons).
     /// 
     /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
@@ -258,43 +432,125 @@ impl Currencies {
         helpers::checked_to_metal(self.weapons, self.keys, key_price)
     }
     
-    /// Checks if the currencies do contain any value.
+    /// Converts currencies to a weapon value using the given key price (represented as weapons),
+    /// additionally rejecting currencies with more than `max_keys` keys (in either direction).
+    /// A key count that large is likely the result of corrupt input. In cases where the number of
+    /// keys exceeds `max_keys` or the result overflows or underflows beyond the limit for
+    /// [`Currency`], `None` will be returned.
     /// 
     /// # Examples
     /// ```
-    /// use tf2_price::Currencies;
+    /// use tf2_price::{Currencies, refined};
     /// 
-    /// assert!(Currencies {
-    ///     keys: 0,
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 5000,
     ///     weapons: 0,
-    /// }.is_empty());
+    /// };
+    /// 
+    /// assert_eq!(currencies.checked_to_weapons_capped(key_price_weapons, 1000), None);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.keys == 0 && self.weapons == 0
+    pub fn checked_to_weapons_capped(
+        &self,
+        key_price_weapons: Currency,
+        max_keys: Currency,
+    ) -> Option<Currency> {
+        if self.keys.saturating_abs() > max_keys {
+            return None;
+        }
+        
+        self.checked_to_weapons(key_price_weapons)
     }
     
-    /// Rounds the weapon value using the given rounding method. Returns a new `Currencies` 
-    /// rather than mutating the original in-place.
+    /// Converts each of the given currencies to a weapon value using the given key price
+    /// (represented as weapons), writing the results into `out`. Each result is the same as
+    /// [`Currencies::checked_to_weapons`]. `out` is cleared first, so a buffer can be reused
+    /// across calls without reallocating.
     /// 
     /// # Examples
     /// ```
-    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// use tf2_price::{Currencies, Currency, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let items = [
+    ///     Currencies { keys: 1, weapons: 0 },
+    ///     Currencies { keys: Currency::MAX, weapons: 0 },
+    /// ];
+    /// let mut out = Vec::new();
+    /// 
+    /// Currencies::checked_to_weapons_batch(&items, key_price_weapons, &mut out);
+    /// 
+    /// assert_eq!(out, [Some(refined!(50)), None]);
+    /// ```
+    pub fn checked_to_weapons_batch(
+        items: &[Currencies],
+        key_price_weapons: Currency,
+        out: &mut Vec<Option<Currency>>,
+    ) {
+        out.clear();
+        out.extend(items.iter().map(|currencies| currencies.checked_to_weapons(key_price_weapons)));
+    }
+    
+    /// Converts currencies to a weapon value using the given key price (represented as weapons).
+    /// In cases where the result overflows or underflows beyond the limit for [`Currency`], the
+    /// exact total is returned as an `i128` in the error, which is useful for diagnostics.
+    /// 
+    /// # Errors
+    /// Errors with the exact total if it is out of bounds for [`Currency`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency, refined};
     /// 
+    /// let key_price_weapons = refined!(50);
     /// let currencies = Currencies {
-    ///     keys: 0,
-    ///     weapons: refined!(1) + scrap!(3),
+    ///     keys: Currency::MAX,
+    ///     weapons: 0,
     /// };
     /// 
-    /// assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(1));
-    /// assert_eq!(currencies.round(&Rounding::UpRefined).weapons, refined!(2));
+    /// assert_eq!(
+    ///     currencies.to_weapons_checked_detailed(key_price_weapons),
+    ///     Err(Currency::MAX as i128 * refined!(50) as i128),
+    /// );
     /// ```
-    pub fn round(mut self, rounding: &Rounding) -> Self {
-        self.weapons = helpers::round_metal(self.weapons, rounding);
-        self
+    pub fn to_weapons_checked_detailed(
+        &self,
+        key_price_weapons: Currency,
+    ) -> Result<Currency, i128> {
+        let weapons = helpers::to_weapons_i128(self.keys, self.weapons, key_price_weapons);
+        
+        Currency::try_from(weapons).map_err(|_error| weapons)
     }
     
-    /// Neatens currencies. If the `weapons` value is over `key_price_weapons`, the `weapons` 
-    /// value will be converted to `keys`, with the remainder remaining as `weapons`.
+    /// Converts currencies to a weapon value using the given key price (represented as weapons),
+    /// raising the key price to `min_price` if it is lower. This guards against a zero or absurdly
+    /// low key price from an external source producing a wildly incorrect value.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(5),
+    /// };
+    /// 
+    /// assert_eq!(currencies.to_weapons_clamped_price(0, refined!(40)), refined!(85));
+    /// assert_eq!(currencies.to_weapons_clamped_price(refined!(50), refined!(40)), refined!(105));
+    /// ```
+    pub fn to_weapons_clamped_price(
+        &self,
+        key_price_weapons: Currency,
+        min_price: Currency,
+    ) -> Currency {
+        self.to_weapons(key_price_weapons.max(min_price))
+    }
+    
+    /// Converts currencies to a weapon value using the given key price (represented as weapons),
+    /// treating negative values as zero. Useful for displays which should never show a negative
+    /// price.
     /// 
     /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
     /// 
@@ -305,1122 +561,5563 @@ impl Currencies {
     /// let key_price_weapons = refined!(50);
     /// let currencies = Currencies {
     ///     keys: 1,
-    ///     weapons: refined!(60),
-    /// }.neaten(key_price_weapons);
+    ///     weapons: -refined!(60),
+    /// };
     /// 
-    /// assert_eq!(
-    ///     currencies,
-    ///     Currencies {
-    ///         keys: 2,
-    ///         weapons: refined!(10),
-    ///     },
-    /// );
+    /// assert_eq!(currencies.to_weapons_nonneg(key_price_weapons), 0);
     /// ```
-    pub fn neaten(&self, key_price_weapons: Currency) -> Self {
-        Self::from_weapons(self.to_weapons(key_price_weapons), key_price_weapons)
+    pub fn to_weapons_nonneg(&self, key_price_weapons: Currency) -> Currency {
+        self.to_weapons(key_price_weapons).max(0)
     }
     
-    /// Checks whether the currencies have enough `keys` and `weapons` to afford the `other` 
-    /// currencies. This is simply `self.keys >= other.keys && self.weapons >= other.weapons`.
+    /// Converts currencies to a weapon value using the average of the given key prices
+    /// (represented as weapons), rounded to the nearest weapon. `None` if `key_prices` is empty.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
     /// 
     /// # Examples
     /// ```
     /// use tf2_price::{Currencies, refined};
     /// 
     /// let currencies = Currencies {
-    ///     keys: 100,
-    ///     weapons: refined!(30),
+    ///     keys: 2,
+    ///     weapons: refined!(5),
     /// };
+    /// let key_prices = [refined!(49), refined!(50), refined!(51)];
     /// 
-    /// // We have at least 50 keys and 30 refined.
-    /// assert!(currencies.can_afford(&Currencies {
-    ///     keys: 50,
-    ///     weapons: refined!(30),
-    /// }));
-    /// // Not enough metal - we can't afford this.
-    /// assert!(!currencies.can_afford(&Currencies {
-    ///     keys: 50,
-    ///     weapons: refined!(100)
-    /// }));
+    /// assert_eq!(currencies.to_weapons_avg_price(&key_prices), Some(refined!(105)));
     /// ```
-    pub fn can_afford(&self, other: &Self) -> bool {
-        self.keys >= other.keys && self.weapons >= other.weapons
+    pub fn to_weapons_avg_price(&self, key_prices: &[Currency]) -> Option<Currency> {
+        if key_prices.is_empty() {
+            return None;
+        }
+        
+        let total = key_prices
+            .iter()
+            .map(|key_price| *key_price as i128)
+            .sum::<i128>();
+        let key_price = helpers::round_div_i128(total, key_prices.len() as i128);
+        
+        Some(self.to_weapons(helpers::saturating_i128_to_currency(key_price)))
     }
     
-    /// Checked integer multiplication. Computes `self * rhs` for each field, returning `None` if 
-    /// overflow occurred.
+    /// Converts currencies to a weapon value as an `f64` using the given key price (represented
+    /// as weapons). The total is computed as an `i128` and only converted to an `f64` at the end,
+    /// which retains much more precision for large values than an `f32`. Since the total is not
+    /// limited to the bounds of [`Currency`], this method does not saturate.
     /// 
     /// # Examples
     /// ```
-    /// use tf2_price::{Currencies, Currency};
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 2_000_000,
+    ///     weapons: 7,
+    /// };
+    /// 
+    /// assert_eq!(currencies.to_weapons_f64(key_price_weapons), 1_800_000_007.0);
+    /// ```
+    pub fn to_weapons_f64(&self, key_price_weapons: Currency) -> f64 {
+        helpers::to_weapons_i128(self.keys, self.weapons, key_price_weapons) as f64
+    }
+    
+    /// Converts currencies to a weapon value using the given key price (represented as weapons),
+    /// handling overflow according to the given [`OverflowPolicy`]. `None` is only returned when
+    /// using [`OverflowPolicy::Checked`] and the result overflows.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency, OverflowPolicy, refined};
     /// 
+    /// let key_price_weapons = refined!(50);
     /// let currencies = Currencies {
     ///     keys: Currency::MAX,
     ///     weapons: 0,
     /// };
     /// 
-    /// // Overflows, returns None.
-    /// assert!(currencies.checked_mul(5).is_none());
+    /// assert_eq!(
+    ///     currencies.to_weapons_policy(key_price_weapons, OverflowPolicy::Saturate),
+    ///     Some(Currency::MAX),
+    /// );
+    /// assert_eq!(
+    ///     currencies.to_weapons_policy(key_price_weapons, OverflowPolicy::Checked),
+    ///     None,
+    /// );
     /// ```
-    pub fn checked_mul(&self, rhs: Currency) -> Option<Self> {
-        let keys = self.keys.checked_mul(rhs)?;
-        let weapons = self.weapons.checked_mul(rhs)?;
-        
-        Some(Self { keys, weapons })
+    pub fn to_weapons_policy(
+        &self,
+        key_price_weapons: Currency,
+        policy: OverflowPolicy,
+    ) -> Option<Currency> {
+        match policy {
+            OverflowPolicy::Saturate => Some(self.to_weapons(key_price_weapons)),
+            OverflowPolicy::Wrap => Some(
+                self.keys.wrapping_mul(key_price_weapons).wrapping_add(self.weapons)
+            ),
+            OverflowPolicy::Checked => self.checked_to_weapons(key_price_weapons),
+        }
     }
     
-    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the 
-    /// division results in overflow.
-    pub fn checked_div(&self, rhs: Currency) -> Option<Self> {
-        let keys = self.keys.checked_div(rhs)?;
-        let weapons = self.weapons.checked_div(rhs)?;
-        
-        Some(Self { keys, weapons })
+    /// Checks if the currencies do contain any value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// assert!(Currencies {
+    ///     keys: 0,
+    ///     weapons: 0,
+    /// }.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.keys == 0 && self.weapons == 0
     }
     
-    /// Adds currencies. `None` if the result overflows integer bounds.
-    pub fn checked_add(&self, other: Self) -> Option<Self> {
-        let keys = self.keys.checked_add(other.keys)?;
-        let weapons = self.weapons.checked_add(other.weapons)?;
-        
-        Some(Self { keys, weapons })
+    /// Checks whether the total value of the currencies is greater than zero using the given key
+    /// price (represented as weapons).
+    /// 
+    /// Currencies which are empty (see [`Currencies::is_empty`]) are never positive. However,
+    /// the reverse is not true: currencies which are not empty may still have a total value of
+    /// zero if the `keys` and `weapons` fields cancel each other out.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: -refined!(10),
+    /// };
+    /// 
+    /// assert!(currencies.is_positive_value(key_price_weapons));
+    /// ```
+    pub fn is_positive_value(&self, key_price_weapons: Currency) -> bool {
+        self.to_weapons(key_price_weapons) > 0
     }
     
-    /// Subtracts currencies. `None` if the result overflows integer bounds.
-    pub fn checked_sub(&self, other: Self) -> Option<Self> {
-        let keys = self.keys.checked_sub(other.keys)?;
-        let weapons = self.weapons.checked_sub(other.weapons)?;
-        
-        Some(Self { keys, weapons })
-    }
-}
-
-/// Comparison with [`FloatCurrencies`] will fail if [`FloatCurrencies`] has a fractional key 
-/// value.
-impl PartialEq<FloatCurrencies> for Currencies {
-    fn eq(&self, other: &FloatCurrencies) -> bool {
-        if let Some(weapons) = helpers::checked_get_weapons_from_metal_float(other.metal) {
-            other.keys.fract() != 0.0 &&
-            self.keys == other.keys as Currency &&
-            self.weapons == weapons
+    /// Checks whether the total value of the currencies is less than zero using the given key
+    /// price (represented as weapons).
+    /// 
+    /// Currencies which are empty (see [`Currencies::is_empty`]) are never negative. When sorting
+    /// using the [`Ord`] implementation, negative values may still sort above empty currencies as
+    /// `keys` are compared before `weapons`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: -refined!(60),
+    /// };
+    /// 
+    /// assert!(currencies.is_negative_value(key_price_weapons));
+    /// ```
+    pub fn is_negative_value(&self, key_price_weapons: Currency) -> bool {
+        self.to_weapons(key_price_weapons) < 0
+    }
+    
+    /// Checks whether the currencies are positive without a key price. Keys dominate weapons, so
+    /// the sign of `keys` is used unless it is zero, in which case the sign of `weapons` is used.
+    /// This matches the [`Ord`] implementation when comparing against empty currencies. Use
+    /// [`Currencies::is_positive_value`] to check the sign of the total value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert!(Currencies { keys: 1, weapons: -refined!(60) }.is_positive());
+    /// assert!(Currencies { keys: 0, weapons: refined!(5) }.is_positive());
+    /// assert!(!Currencies { keys: 0, weapons: 0 }.is_positive());
+    /// ```
+    pub fn is_positive(&self) -> bool {
+        self.keys > 0 || (self.keys == 0 && self.weapons > 0)
+    }
+    
+    /// Checks whether the currencies are negative without a key price. Keys dominate weapons, so
+    /// the sign of `keys` is used unless it is zero, in which case the sign of `weapons` is used.
+    /// This matches the [`Ord`] implementation when comparing against empty currencies. Use
+    /// [`Currencies::is_negative_value`] to check the sign of the total value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert!(Currencies { keys: -1, weapons: refined!(60) }.is_negative());
+    /// assert!(Currencies { keys: 0, weapons: -refined!(5) }.is_negative());
+    /// assert!(!Currencies { keys: 0, weapons: 0 }.is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool {
+        self.keys < 0 || (self.keys == 0 && self.weapons < 0)
+    }
+    
+    /// Compares the total values of these currencies and `other` using the given key price
+    /// (represented as weapons). Currencies with equal values but different splits e.g. `1 key`
+    /// and `50 ref` at a key price of 50 ref are ordered using the [`Ord`] implementation, so
+    /// this is a total order consistent with [`Eq`] which is suitable for sorting or use in a
+    /// [`BTreeSet`](std::collections::BTreeSet).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// use std::cmp::Ordering;
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let key = Currencies { keys: 1, weapons: 0 };
+    /// let metal = Currencies { keys: 0, weapons: refined!(50) };
+    /// 
+    /// assert_eq!(key.cmp_by_value_then_fields(&metal, key_price_weapons), Ordering::Greater);
+    /// assert_eq!(metal.cmp_by_value_then_fields(&key, key_price_weapons), Ordering::Less);
+    /// ```
+    pub fn cmp_by_value_then_fields(&self, other: &Self, key_price_weapons: Currency) -> Ordering {
+        // Compared as i128 so that values beyond the bounds of Currency are not clamped together.
+        let value = |currencies: &Self| {
+            helpers::to_weapons_i128(currencies.keys, currencies.weapons, key_price_weapons)
+        };
+        
+        value(self)
+            .cmp(&value(other))
+            .then_with(|| self.cmp(other))
+    }
+    
+    /// Rounds the weapon value using the given rounding method. Returns a new `Currencies` 
+    /// rather than mutating the original in-place.
+    /// 
+    /// Only the `weapons` field is rounded. The currencies are not neatened, meaning `weapons` is
+    /// never converted into `keys` even if the rounded value exceeds the price of a key. Use
+    /// [`Currencies::neaten`] to do so.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 0,
+    ///     weapons: refined!(1) + scrap!(3),
+    /// };
+    /// 
+    /// assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(1));
+    /// assert_eq!(currencies.round(&Rounding::UpRefined).weapons, refined!(2));
+    /// ```
+    pub fn round(mut self, rounding: &Rounding) -> Self {
+        self.round_mut(rounding);
+        self
+    }
+    
+    /// Rounds the weapon value in place using the given rounding method. Same as
+    /// [`Currencies::round`] but mutates these currencies rather than consuming them.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let mut currencies = Currencies {
+    ///     keys: 0,
+    ///     weapons: refined!(1) + scrap!(3),
+    /// };
+    /// 
+    /// currencies.round_mut(&Rounding::UpRefined);
+    /// assert_eq!(currencies.weapons, refined!(2));
+    /// ```
+    pub fn round_mut(&mut self, rounding: &Rounding) {
+        self.weapons = helpers::round_metal(self.weapons, rounding);
+    }
+    
+    /// Rounds the weapon value using the given rounding method, then neatens the currencies using
+    /// the given key price (represented as weapons). Same as calling [`Currencies::round`] then
+    /// [`Currencies::neaten`]. If the key price is zero, the currencies are only rounded.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(49) + scrap!(5),
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.round_and_neaten(&Rounding::UpRefined, key_price_weapons),
+    ///     Currencies { keys: 2, weapons: 0 },
+    /// );
+    /// ```
+    pub fn round_and_neaten(self, rounding: &Rounding, key_price_weapons: Currency) -> Self {
+        let rounded = self.round(rounding);
+        
+        if key_price_weapons == 0 {
+            return rounded;
+        }
+        
+        rounded.neaten(key_price_weapons)
+    }
+    
+    /// Rounds the weapon value both down and up at the granularity of the given rounding method,
+    /// returning `(down, up)`. The original value always falls within this band. For example,
+    /// [`Rounding::Refined`], [`Rounding::UpRefined`], and [`Rounding::DownRefined`] all produce
+    /// the values rounded using [`Rounding::DownRefined`] and [`Rounding::UpRefined`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(23) + scrap!(4),
+    /// };
+    /// let (down, up) = currencies.round_band(&Rounding::Refined);
+    /// 
+    /// assert_eq!(down, Currencies { keys: 1, weapons: refined!(23) });
+    /// assert_eq!(up, Currencies { keys: 1, weapons: refined!(24) });
+    /// ```
+    pub fn round_band(&self, rounding_granularity: &Rounding) -> (Self, Self) {
+        let (down, up) = match rounding_granularity {
+            Rounding::UpScrap |
+            Rounding::DownScrap => (Rounding::DownScrap, Rounding::UpScrap),
+            Rounding::Reclaimed |
+            Rounding::UpReclaimed |
+            Rounding::DownReclaimed => (Rounding::DownReclaimed, Rounding::UpReclaimed),
+            Rounding::Refined |
+            Rounding::UpRefined |
+            Rounding::DownRefined => (Rounding::DownRefined, Rounding::UpRefined),
+            Rounding::None => (Rounding::None, Rounding::None),
+        };
+        
+        (self.round(&down), self.round(&up))
+    }
+    
+    /// Neatens currencies. If the `weapons` value is over `key_price_weapons`, the `weapons` 
+    /// value will be converted to `keys`, with the remainder remaining as `weapons`.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Panics
+    /// Panics if `key_price_weapons` is zero. Use [`Currencies::checked_neaten`] if the key price
+    /// may be zero.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(60),
+    /// }.neaten(key_price_weapons);
+    /// 
+    /// assert_eq!(
+    ///     currencies,
+    ///     Currencies {
+    ///         keys: 2,
+    ///         weapons: refined!(10),
+    ///     },
+    /// );
+    /// ```
+    pub fn neaten(&self, key_price_weapons: Currency) -> Self {
+        Self::from_weapons(self.to_weapons(key_price_weapons), key_price_weapons)
+    }
+    
+    /// Neatens currencies. If the `weapons` value is over `key_price_weapons`, the `weapons`
+    /// value will be converted to `keys`, with the remainder remaining as `weapons`.
+    /// 
+    /// Checks for safe conversion. In cases where the total weapon value overflows or underflows
+    /// beyond the limit for [`Currency`], or the key price is zero, `None` will be returned.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(60),
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.checked_neaten(key_price_weapons),
+    ///     Some(Currencies { keys: 2, weapons: refined!(10) }),
+    /// );
+    /// assert_eq!(
+    ///     Currencies { keys: Currency::MAX, weapons: 0 }.checked_neaten(key_price_weapons),
+    ///     None,
+    /// );
+    /// ```
+    pub fn checked_neaten(&self, key_price_weapons: Currency) -> Option<Self> {
+        let weapons = self.checked_to_weapons(key_price_weapons)?;
+        
+        Self::checked_from_weapons(weapons, key_price_weapons)
+    }
+    
+    /// Normalizes currencies by borrowing from or carrying into `keys` using the given key price
+    /// (represented as weapons). Unlike [`Currencies::neaten`], the total value is never clamped
+    /// before being split, so large key values are adjusted only by the number of keys borrowed
+    /// or carried rather than collapsing when the total overflows.
+    /// 
+    /// If the total value is non-negative, the resulting `weapons` is guaranteed to be in
+    /// `0..key_price_weapons`. If the total value is negative, both fields are non-positive and
+    /// `weapons` is in `-key_price_weapons + 1..=0`, matching [`Currencies::neaten`].
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Panics
+    /// Panics if `key_price_weapons` is zero.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// 
+    /// assert_eq!(
+    ///     Currencies { keys: 2, weapons: -refined!(60) }.normalize(key_price_weapons),
+    ///     Currencies { keys: 0, weapons: refined!(40) },
+    /// );
+    /// assert_eq!(
+    ///     Currencies { keys: Currency::MAX, weapons: -1 }.normalize(key_price_weapons),
+    ///     Currencies { keys: Currency::MAX - 1, weapons: key_price_weapons - 1 },
+    /// );
+    /// ```
+    pub fn normalize(&self, key_price_weapons: Currency) -> Self {
+        assert!(key_price_weapons != 0, "key price must not be zero");
+        
+        let total = helpers::to_weapons_i128(self.keys, self.weapons, key_price_weapons);
+        let key_price_weapons = key_price_weapons as i128;
+        let keys = self.keys as i128;
+        let weapons = self.weapons as i128;
+        let (carry, weapons) = if total >= 0 {
+            (weapons.div_euclid(key_price_weapons), weapons.rem_euclid(key_price_weapons))
         } else {
-            false
+            // Mirrors the non-negative case so that weapons is never positive.
+            (-(-weapons).div_euclid(key_price_weapons), -(-weapons).rem_euclid(key_price_weapons))
+        };
+        
+        Self {
+            keys: helpers::saturating_i128_to_currency(keys + carry),
+            // Always within the bounds of the key price.
+            weapons: weapons as Currency,
+        }
+    }
+    
+    /// Snaps the total value of the currencies to a "nice" price which is easier for humans to
+    /// read using the given key price (represented as weapons). The value is rounded to the
+    /// nearest:
+    /// - Whole refined if the value is less than 1 key.
+    /// - Half key if the value is at least 1 key but less than 10 keys.
+    /// - Whole key if the value is 10 keys or more.
+    /// 
+    /// The result is then split into keys and weapons using [`Currencies::from_weapons`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(18) + scrap!(4),
+    /// };
+    /// 
+    /// // 1.37 keys snaps to 1.5 keys.
+    /// assert_eq!(
+    ///     currencies.snap_to_nice(key_price_weapons),
+    ///     Currencies { keys: 1, weapons: refined!(25) },
+    /// );
+    /// ```
+    pub fn snap_to_nice(&self, key_price_weapons: Currency) -> Self {
+        // Values at or above this many keys are snapped to whole keys.
+        const WHOLE_KEYS_THRESHOLD: i128 = 10;
+        
+        let weapons = self.to_weapons(key_price_weapons) as i128;
+        let key_price = key_price_weapons as i128;
+        let magnitude = weapons.abs();
+        let snapped = if magnitude < key_price.abs() {
+            helpers::round_div_i128(weapons, ONE_REF as i128) * ONE_REF as i128
+        } else if magnitude < (key_price * WHOLE_KEYS_THRESHOLD).abs() {
+            // Counted in half keys.
+            helpers::round_div_i128(weapons * 2, key_price) * key_price / 2
+        } else {
+            helpers::round_div_i128(weapons, key_price) * key_price
+        };
+        
+        Self::from_weapons(helpers::saturating_i128_to_currency(snapped), key_price_weapons)
+    }
+    
+    /// Checks whether the currencies have enough `keys` and `weapons` to afford the `other` 
+    /// currencies. This is simply `self.keys >= other.keys && self.weapons >= other.weapons`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 100,
+    ///     weapons: refined!(30),
+    /// };
+    /// 
+    /// // We have at least 50 keys and 30 refined.
+    /// assert!(currencies.can_afford(&Currencies {
+    ///     keys: 50,
+    ///     weapons: refined!(30),
+    /// }));
+    /// // Not enough metal - we can't afford this.
+    /// assert!(!currencies.can_afford(&Currencies {
+    ///     keys: 50,
+    ///     weapons: refined!(100)
+    /// }));
+    /// ```
+    pub fn can_afford(&self, other: &Self) -> bool {
+        self.keys >= other.keys && self.weapons >= other.weapons
+    }
+    
+    /// Gets which fields of these currencies are insufficient to afford the `cost` currencies as
+    /// `(keys_short, metal_short)`. `None` if the cost can be afforded (see
+    /// [`Currencies::can_afford`]).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 100,
+    ///     weapons: refined!(30),
+    /// };
+    /// 
+    /// assert_eq!(currencies.shortfall(&Currencies { keys: 50, weapons: refined!(30) }), None);
+    /// assert_eq!(
+    ///     currencies.shortfall(&Currencies { keys: 50, weapons: refined!(100) }),
+    ///     Some((false, true)),
+    /// );
+    /// ```
+    pub fn shortfall(&self, cost: &Self) -> Option<(bool, bool)> {
+        if self.can_afford(cost) {
+            return None;
         }
+        
+        Some((self.keys < cost.keys, self.weapons < cost.weapons))
+    }
+    
+    /// Gets the signed difference between these currencies and the `other` currencies for each
+    /// field as `(keys, weapons)`. Values are widened to `i64` and the subtraction is
+    /// [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 3,
+    ///     weapons: refined!(10),
+    /// };
+    /// let other = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(20),
+    /// };
+    /// 
+    /// assert_eq!(currencies.diff(&other), (2, -refined!(10)));
+    /// ```
+    // The conversions are only needed when the `b32` feature is enabled.
+    #[allow(clippy::useless_conversion)]
+    pub fn diff(&self, other: &Self) -> (i64, i64) {
+        (
+            i64::from(self.keys).saturating_sub(i64::from(other.keys)),
+            i64::from(self.weapons).saturating_sub(i64::from(other.weapons)),
+        )
+    }
+    
+    /// Checked integer multiplication. Computes `self * rhs` for each field, returning `None` if 
+    /// overflow occurred.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: Currency::MAX,
+    ///     weapons: 0,
+    /// };
+    /// 
+    /// // Overflows, returns None.
+    /// assert!(currencies.checked_mul(5).is_none());
+    /// ```
+    pub fn checked_mul(&self, rhs: Currency) -> Option<Self> {
+        let keys = self.keys.checked_mul(rhs)?;
+        let weapons = self.weapons.checked_mul(rhs)?;
+        
+        Some(Self { keys, weapons })
+    }
+    
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the 
+    /// division results in overflow.
+    pub fn checked_div(&self, rhs: Currency) -> Option<Self> {
+        let keys = self.keys.checked_div(rhs)?;
+        let weapons = self.weapons.checked_div(rhs)?;
+        
+        Some(Self { keys, weapons })
+    }
+    
+    /// Adds currencies. `None` if the result overflows integer bounds.
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        let keys = self.keys.checked_add(other.keys)?;
+        let weapons = self.weapons.checked_add(other.weapons)?;
+        
+        Some(Self { keys, weapons })
+    }
+    
+    /// Subtracts currencies. `None` if the result overflows integer bounds.
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        let keys = self.keys.checked_sub(other.keys)?;
+        let weapons = self.weapons.checked_sub(other.weapons)?;
+        
+        Some(Self { keys, weapons })
+    }
+    
+    /// Adds currencies, clamping each field at the integer bounds. Same as the `+` operator.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self + other
+    }
+    
+    /// Subtracts currencies, clamping each field at the integer bounds. Same as the `-` operator.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self - other
+    }
+    
+    /// Adds currencies using checked addition for `keys` and saturating addition for `weapons`.
+    /// `None` if the `keys` overflow integer bounds. This is useful when key counts must always
+    /// be exact.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: Currency::MAX,
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.add_strict_keys(&Currencies { keys: 1, weapons: 1 }),
+    ///     Some(Currencies { keys: 2, weapons: Currency::MAX }),
+    /// );
+    /// assert!(currencies.add_strict_keys(&Currencies { keys: Currency::MAX, weapons: 0 }).is_none());
+    /// ```
+    pub fn add_strict_keys(&self, other: &Self) -> Option<Self> {
+        let keys = self.keys.checked_add(other.keys)?;
+        let weapons = self.weapons.saturating_add(other.weapons);
+        
+        Some(Self { keys, weapons })
+    }
+    
+    /// Negates currencies. `None` if either field is [`Currency::MIN`], which cannot be negated
+    /// within integer bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency};
+    /// 
+    /// assert_eq!(
+    ///     Currencies { keys: 1, weapons: -5 }.checked_neg(),
+    ///     Some(Currencies { keys: -1, weapons: 5 }),
+    /// );
+    /// assert!(Currencies { keys: Currency::MIN, weapons: 0 }.checked_neg().is_none());
+    /// ```
+    pub fn checked_neg(&self) -> Option<Self> {
+        let keys = self.keys.checked_neg()?;
+        let weapons = self.weapons.checked_neg()?;
+        
+        Some(Self { keys, weapons })
+    }
+    
+    /// Takes the absolute value of each field. Mixed-sign currencies e.g. positive keys and
+    /// negative weapons are treated by field, so `{ keys: 1, weapons: -5 }` becomes
+    /// `{ keys: 1, weapons: 5 }` rather than the magnitude of the total value.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// [`Currency::MIN`] becomes [`Currency::MAX`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert_eq!(
+    ///     Currencies { keys: -2, weapons: -refined!(5) }.abs(),
+    ///     Currencies { keys: 2, weapons: refined!(5) },
+    /// );
+    /// ```
+    pub fn abs(self) -> Self {
+        Self {
+            keys: self.keys.saturating_abs(),
+            weapons: self.weapons.saturating_abs(),
+        }
+    }
+    
+    /// Divides the value of these currencies by the value of the `divisor` currencies using the
+    /// given key price (represented as weapons). Returns the number of times the `divisor` fits
+    /// into these currencies along with the remaining value as [`Currencies`]. `None` if the
+    /// value of the `divisor` is zero, the key price is zero, or converting either value to
+    /// weapons or the division results in overflow.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(20),
+    /// };
+    /// let divisor = Currencies {
+    ///     keys: 0,
+    ///     weapons: refined!(30),
+    /// };
+    /// let (count, remainder) = currencies.checked_div_by(&divisor, key_price_weapons).unwrap();
+    /// 
+    /// assert_eq!(count, 2);
+    /// assert_eq!(remainder, Currencies { keys: 0, weapons: refined!(10) });
+    /// ```
+    pub fn checked_div_by(
+        &self,
+        divisor: &Self,
+        key_price_weapons: Currency,
+    ) -> Option<(Currency, Self)> {
+        let weapons = self.checked_to_weapons(key_price_weapons)?;
+        let divisor_weapons = divisor.checked_to_weapons(key_price_weapons)?;
+        let count = weapons.checked_div(divisor_weapons)?;
+        let remainder = weapons.checked_rem(divisor_weapons)?;
+        
+        Some((count, Self::checked_from_weapons(remainder, key_price_weapons)?))
+    }
+    
+    /// Divides the value of these currencies by the value of `divisor` using the given key price
+    /// (represented as weapons), rounding down to the nearest integer. This is useful for
+    /// determining how many times more valuable one price is than another. Returns `0` if the
+    /// divisor has no value.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: 0,
+    /// };
+    /// let divisor = Currencies {
+    ///     keys: 0,
+    ///     weapons: refined!(15),
+    /// };
+    /// 
+    /// assert_eq!(currencies.div_value_floor(&divisor, key_price_weapons), 3);
+    /// ```
+    pub fn div_value_floor(&self, divisor: &Self, key_price_weapons: Currency) -> Currency {
+        let weapons = self.to_weapons(key_price_weapons) as i128;
+        let divisor_weapons = divisor.to_weapons(key_price_weapons) as i128;
+        
+        if divisor_weapons == 0 {
+            return 0;
+        }
+        
+        let mut quotient = weapons / divisor_weapons;
+        
+        // Division truncates towards zero, so negative results with a remainder are one too high.
+        if weapons % divisor_weapons != 0 && (weapons < 0) != (divisor_weapons < 0) {
+            quotient -= 1;
+        }
+        
+        helpers::saturating_i128_to_currency(quotient)
+    }
+    
+    /// Checks whether the value of these currencies is an exact integer multiple of the value of
+    /// `unit` using the given key price (represented as weapons). Always `false` if `unit` has no
+    /// value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let bundle = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(10),
+    /// };
+    /// let unit = Currencies {
+    ///     keys: 0,
+    ///     weapons: refined!(20),
+    /// };
+    /// 
+    /// assert!(bundle.is_value_multiple_of(&unit, key_price_weapons));
+    /// ```
+    pub fn is_value_multiple_of(&self, unit: &Self, key_price_weapons: Currency) -> bool {
+        // Computed as i128 so that Currency::MIN % -1 does not overflow.
+        let weapons = self.to_weapons(key_price_weapons) as i128;
+        let unit_weapons = unit.to_weapons(key_price_weapons) as i128;
+        
+        unit_weapons != 0 && weapons % unit_weapons == 0
+    }
+    
+    /// Formats the currencies as a difference in price, with a leading `+` or `-` on each
+    /// non-zero component e.g. `"+2 keys, -5 ref"`. Zero components are omitted and currencies
+    /// with no value are formatted as `"±0"`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(11),
+    /// };
+    /// 
+    /// assert_eq!(currencies.format_delta(), "+2 keys, +11 ref");
+    /// ```
+    pub fn format_delta(&self) -> String {
+        let mut components = Vec::with_capacity(2);
+        
+        if self.keys != 0 {
+            components.push(format!(
+                "{:+} {}",
+                self.keys,
+                helpers::pluralize(self.keys.saturating_abs(), KEY_SYMBOL, KEYS_SYMBOL),
+            ));
+        }
+        
+        if self.weapons != 0 {
+            components.push(format!(
+                "{:+} {}",
+                helpers::get_metal_float_from_weapons(self.weapons),
+                METAL_SYMBOL,
+            ));
+        }
+        
+        if components.is_empty() {
+            return "±0".into();
+        }
+        
+        components.join(", ")
+    }
+    
+    /// Returns the `floor` currencies if the value of these currencies is below the value of the
+    /// `floor` using the given key price (represented as weapons), otherwise returns these
+    /// currencies unchanged. This is useful for ensuring a computed price never drops below a
+    /// market minimum.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let floor = Currencies { keys: 0, weapons: scrap!(1) };
+    /// let currencies = Currencies { keys: 0, weapons: 1 };
+    /// 
+    /// assert_eq!(currencies.at_least(&floor, key_price_weapons), floor);
+    /// ```
+    pub fn at_least(&self, floor: &Self, key_price_weapons: Currency) -> Self {
+        if self.to_weapons(key_price_weapons) < floor.to_weapons(key_price_weapons) {
+            *floor
+        } else {
+            *self
+        }
+    }
+    
+    /// Gets the number of physical items needed to trade these currencies. This is the number of
+    /// keys plus the number of refined, reclaimed, scrap, and weapons the metal breaks down into
+    /// using the fewest items possible. Negative values are counted by their magnitude.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, reclaimed, scrap};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(3) + reclaimed!(2) + scrap!(1) + 1,
+    /// };
+    /// 
+    /// // 2 keys, 3 refined, 2 reclaimed, 1 scrap, and 1 weapon.
+    /// assert_eq!(currencies.total_items(), 9);
+    /// ```
+    pub fn total_items(&self) -> Currency {
+        [
+            helpers::refined_part(self.weapons),
+            helpers::reclaimed_part(self.weapons),
+            helpers::scrap_part(self.weapons),
+            helpers::weapon_part(self.weapons),
+        ]
+            .into_iter()
+            .fold(self.keys.saturating_abs(), |total, count| total.saturating_add(count.abs()))
+    }
+    
+    /// Gets the components of these currencies, including the refined float value and the
+    /// fewest refined, reclaimed, scrap, and weapons needed to make up the metal value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Components, Currencies, refined, scrap};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(23) + scrap!(4),
+    /// };
+    /// let Components { keys, refined, reclaimed, scrap, .. } = currencies.components();
+    /// 
+    /// assert_eq!((keys, refined, reclaimed, scrap), (2, 23, 1, 1));
+    /// ```
+    pub fn components(&self) -> Components {
+        Components {
+            keys: self.keys,
+            weapons: self.weapons,
+            refined_float: helpers::get_metal_float_from_weapons(self.weapons),
+            refined: helpers::refined_part(self.weapons),
+            reclaimed: helpers::reclaimed_part(self.weapons),
+            scrap: helpers::scrap_part(self.weapons),
+            weapon: helpers::weapon_part(self.weapons),
+        }
+    }
+    
+    /// Breaks down the metal value into the fewest refined, reclaimed, scrap, and weapons needed
+    /// to make it up. Keys are not included.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, MetalBreakdown, refined, scrap};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(23) + scrap!(4) + 1,
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.metal_breakdown(),
+    ///     MetalBreakdown {
+    ///         refined: 23,
+    ///         reclaimed: 1,
+    ///         scrap: 1,
+    ///         weapon: 1,
+    ///     },
+    /// );
+    /// ```
+    pub fn metal_breakdown(&self) -> MetalBreakdown {
+        crate::metal_breakdown(self.weapons)
+    }
+    
+    /// Parses a price from a string, accepting the keywords `"free"` and `"offer"` (ignoring
+    /// case) in addition to numeric prices. This allows a price listed as free to be
+    /// distinguished from a price which is zero.
+    /// 
+    /// # Errors
+    /// Errors if the string is not a keyword and fails to parse into [`Currencies`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, ParsedPrice};
+    /// 
+    /// assert_eq!(Currencies::parse_special("free").unwrap(), ParsedPrice::Free);
+    /// assert_eq!(Currencies::parse_special("Offer").unwrap(), ParsedPrice::Offer);
+    /// assert_eq!(
+    ///     Currencies::parse_special("2 keys").unwrap(),
+    ///     ParsedPrice::Value(Currencies { keys: 2, weapons: 0 }),
+    /// );
+    /// ```
+    pub fn parse_special(string: &str) -> Result<ParsedPrice, ParseError> {
+        let trimmed = string.trim();
+        
+        if trimmed.eq_ignore_ascii_case(FREE_KEYWORD) {
+            Ok(ParsedPrice::Free)
+        } else if trimmed.eq_ignore_ascii_case(OFFER_KEYWORD) {
+            Ok(ParsedPrice::Offer)
+        } else {
+            Ok(ParsedPrice::Value(string.parse::<Self>()?))
+        }
+    }
+    
+    /// Finds the candidate closest in value to these currencies using the given key price
+    /// (represented as weapons). Returns the index of the closest candidate along with the
+    /// distance in weapons, or `None` if there are no candidates. Ties go to the candidate with
+    /// the lower index.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let candidates = [
+    ///     Currencies { keys: 1, weapons: 0 },
+    ///     Currencies { keys: 0, weapons: refined!(40) },
+    /// ];
+    /// let currencies = Currencies {
+    ///     keys: 0,
+    ///     weapons: refined!(43),
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.nearest_in(&candidates, key_price_weapons),
+    ///     Some((1, refined!(3))),
+    /// );
+    /// ```
+    pub fn nearest_in(
+        &self,
+        candidates: &[Currencies],
+        key_price_weapons: Currency,
+    ) -> Option<(usize, Currency)> {
+        let value = helpers::to_weapons_i128(self.keys, self.weapons, key_price_weapons);
+        
+        candidates
+            .iter()
+            .map(|candidate| {
+                helpers::to_weapons_i128(candidate.keys, candidate.weapons, key_price_weapons)
+            })
+            .map(|candidate_value| (candidate_value - value).abs())
+            .enumerate()
+            // min_by_key returns the first minimum so ties go to the lower index.
+            .min_by_key(|(_index, distance)| *distance)
+            .map(|(index, distance)| (index, helpers::saturating_i128_to_currency(distance)))
+    }
+    
+    /// Scales the total value of these currencies by `factor` using the given key price
+    /// (represented as weapons), then converts the result back into keys and weapons. Unlike
+    /// multiplying by an `f32`, which scales each field independently, metal which adds up to a
+    /// key after scaling is carried over into keys.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(30),
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.scale_value(2.0, key_price_weapons),
+    ///     Currencies { keys: 3, weapons: refined!(10) },
+    /// );
+    /// ```
+    pub fn scale_value(&self, factor: f32, key_price_weapons: Currency) -> Self {
+        let weapons = self.to_weapons_f64(key_price_weapons) * factor as f64;
+        
+        // Casting a float to an integer saturates at the bounds of the integer.
+        Self::from_weapons(weapons.round() as Currency, key_price_weapons)
+    }
+    
+    /// Creates currencies from keys and an integer number of hundredths of a refined e.g. `2344`
+    /// for `23.44` refined. The metal is converted to weapons using integer arithmetic, avoiding
+    /// the imprecision of parsing a float. Values which do not land on a weapon are rounded to the
+    /// nearest weapon.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// assert_eq!(
+    ///     Currencies::from_refined_hundredths(2, 2344),
+    ///     Currencies { keys: 2, weapons: refined!(23) + scrap!(4) },
+    /// );
+    /// ```
+    pub fn from_refined_hundredths(keys: Currency, hundredths: Currency) -> Self {
+        // Computed as an i128 so the multiplication cannot overflow. The result is always within
+        // bounds since the hundredths are scaled down.
+        let weapons = helpers::round_div_i128(hundredths as i128 * ONE_REF as i128, 100);
+        
+        Self {
+            keys,
+            weapons: weapons as Currency,
+        }
+    }
+    
+    /// Formats the value of these currencies as both fractional keys and refined using the given
+    /// key price (represented as weapons) e.g. `"1.73 keys (86.5 ref)"`. Keys are rounded to 2
+    /// decimal places. If the key price is zero, only the refined form is shown.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, reclaimed};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(36) + reclaimed!(1) + 3,
+    /// };
+    /// 
+    /// assert_eq!(currencies.dual_display(key_price_weapons), "1.73 keys (86.5 ref)");
+    /// ```
+    pub fn dual_display(&self, key_price_weapons: Currency) -> String {
+        let weapons = self.to_weapons(key_price_weapons);
+        let refined = helpers::get_metal_float_from_weapons(weapons);
+        
+        if key_price_weapons == 0 {
+            return format!("{refined} {METAL_SYMBOL}");
+        }
+        
+        let keys = ((weapons as f64 / key_price_weapons as f64) * 100.0).round() / 100.0;
+        let keys = keys as f32;
+        
+        format!(
+            "{keys} {} ({refined} {METAL_SYMBOL})",
+            helpers::pluralize_float(keys, KEY_SYMBOL, KEYS_SYMBOL),
+        )
+    }
+    
+    /// Applies the growth `factor` to the total value of these currencies `periods` times using
+    /// the given key price (represented as weapons), then converts the result back into keys and
+    /// weapons. The growth is computed as an `f64` for precision and rounded to the nearest weapon.
+    /// 
+    /// If `periods` is zero the currencies are returned unchanged. Otherwise, the result is
+    /// neatened, so excess weapons are carried into keys.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: 0,
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.compound(1.1, 2, key_price_weapons),
+    ///     Currencies { keys: 1, weapons: refined!(10) + 9 },
+    /// );
+    /// ```
+    pub fn compound(&self, factor: f32, periods: u32, key_price_weapons: Currency) -> Self {
+        if periods == 0 {
+            return *self;
+        }
+        
+        let growth = (factor as f64).powf(periods as f64);
+        let weapons = self.to_weapons_f64(key_price_weapons) * growth;
+        
+        // Casting a float to an integer saturates at the bounds of the integer.
+        Self::from_weapons(weapons.round() as Currency, key_price_weapons)
+    }
+    
+    /// Formats these currencies as a string after rounding a copy of them. The currencies
+    /// themselves are not modified, so the stored precision is preserved.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(23) + scrap!(4),
+    /// };
+    /// 
+    /// assert_eq!(currencies.to_string_rounded(&Rounding::DownRefined), "2 keys, 23 ref");
+    /// ```
+    pub fn to_string_rounded(&self, rounding: &Rounding) -> String {
+        self.round(rounding).to_string()
+    }
+    
+    /// Sets the metal value from a float value in refined e.g. `1.33`. The value is rounded to
+    /// the nearest weapon.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let mut currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: 0,
+    /// };
+    /// 
+    /// currencies.set_metal_from_float(1.33);
+    /// 
+    /// assert_eq!(currencies, Currencies { keys: 1, weapons: refined!(1) + scrap!(3) });
+    /// ```
+    pub fn set_metal_from_float(&mut self, refined: f32) {
+        self.weapons = helpers::get_weapons_from_metal_float(refined);
+    }
+    
+    /// Sets the metal value from a float value in refined e.g. `1.33`. The value is rounded to
+    /// the nearest weapon.
+    /// 
+    /// Checks for safe conversion. The metal value is left unchanged on error.
+    /// 
+    /// # Errors
+    /// Errors if the value is NaN, infinite, or out of bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let mut currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: 0,
+    /// };
+    /// 
+    /// assert!(currencies.try_set_metal_from_float(1.33).is_ok());
+    /// assert_eq!(currencies, Currencies { keys: 1, weapons: refined!(1) + scrap!(3) });
+    /// assert!(currencies.try_set_metal_from_float(f32::NAN).is_err());
+    /// ```
+    pub fn try_set_metal_from_float(
+        &mut self,
+        refined: f32,
+    ) -> Result<(), TryFromFloatCurrenciesError> {
+        self.weapons = helpers::checked_get_weapons_from_metal_float(refined)
+            .ok_or(TryFromFloatCurrenciesError::OutOfBounds {
+                value: refined,
+            })?;
+        
+        Ok(())
+    }
+    
+    /// Ensures the currencies have a value by returning [`Currencies::MIN_TRADEABLE`] if they
+    /// are empty, and the currencies otherwise. This prevents a price from accidentally being
+    /// listed for nothing.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// assert_eq!(Currencies::default().ensure_tradeable(), Currencies::MIN_TRADEABLE);
+    /// ```
+    pub fn ensure_tradeable(&self) -> Self {
+        if self.is_empty() {
+            Self::MIN_TRADEABLE
+        } else {
+            *self
+        }
+    }
+    
+    /// Converts currencies to a float value in refined using the given key price (represented as
+    /// weapons). Like [`get_metal_float_from_weapons`](crate::get_metal_float_from_weapons), the
+    /// result is truncated to 2 decimal places.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic). Since
+    /// the result is an `f32`, precision is lost for very large values.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(23) + scrap!(4),
+    /// };
+    /// 
+    /// assert_eq!(currencies.to_refined_f32(key_price_weapons), 123.44);
+    /// ```
+    pub fn to_refined_f32(&self, key_price_weapons: Currency) -> f32 {
+        helpers::get_metal_float_from_weapons(self.to_weapons(key_price_weapons))
+    }
+    
+    /// Converts the total value of these currencies into a fractional number of keys using the
+    /// given key price (represented as weapons) e.g. `1.5` keys. If the key price is zero, the
+    /// result is infinite, or NaN if the currencies have no value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(60);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(30),
+    /// };
+    /// 
+    /// assert_eq!(currencies.to_keys_f32(key_price_weapons), 1.5);
+    /// ```
+    pub fn to_keys_f32(&self, key_price_weapons: Currency) -> f32 {
+        self.to_weapons(key_price_weapons) as f32 / key_price_weapons as f32
+    }
+    
+    /// Converts these currencies into [`FloatCurrencies`] with the metal folded into a fractional
+    /// number of keys using the given key price (represented as weapons). See
+    /// [`Currencies::to_keys_f32`]. If the key price is zero, keys cannot be derived from metal,
+    /// so the metal is left in place the same as converting using [`FloatCurrencies::from`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, FloatCurrencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(60);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(30),
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.to_float_keys(key_price_weapons),
+    ///     FloatCurrencies { keys: 1.5, metal: 0.0 },
+    /// );
+    /// ```
+    pub fn to_float_keys(&self, key_price_weapons: Currency) -> FloatCurrencies {
+        if key_price_weapons == 0 {
+            return FloatCurrencies::from(self);
+        }
+        
+        FloatCurrencies {
+            keys: self.to_keys_f32(key_price_weapons),
+            metal: 0.0,
+        }
+    }
+    
+    /// Blends the value of these currencies with the value of `other` using the given key price
+    /// (represented as weapons). `self_weight` is the weight given to these currencies, from
+    /// `0.0` to `1.0`, with `other` receiving the remaining weight. Weights outside of this range
+    /// are clamped. The result is rounded to the nearest weapon.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let reference = Currencies { keys: 1, weapons: 0 };
+    /// let recent = Currencies { keys: 0, weapons: refined!(40) };
+    /// 
+    /// assert_eq!(
+    ///     reference.blend(&recent, 0.75, key_price_weapons),
+    ///     Currencies { keys: 0, weapons: refined!(47) + 9 },
+    /// );
+    /// ```
+    pub fn blend(&self, other: &Self, self_weight: f32, key_price_weapons: Currency) -> Self {
+        let self_weight = self_weight.clamp(0.0, 1.0) as f64;
+        let weapons = self.to_weapons_f64(key_price_weapons) * self_weight
+            + other.to_weapons_f64(key_price_weapons) * (1.0 - self_weight);
+        
+        // Casting a float to an integer saturates at the bounds of the integer.
+        Self::from_weapons(weapons.round() as Currency, key_price_weapons)
+    }
+    
+    /// Formats the currencies as a string using the given [`DisplayOptions`]. The default
+    /// options produce the same string as the [`Display`](fmt::Display) implementation.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, DisplayOptions};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: 0,
+    /// };
+    /// let options = DisplayOptions::new().always_show_metal(true);
+    /// 
+    /// assert_eq!(currencies.format_with(&DisplayOptions::default()), "2 keys");
+    /// assert_eq!(currencies.format_with(&options), "2 keys, 0 ref");
+    /// ```
+    pub fn format_with(&self, options: &DisplayOptions) -> String {
+        let show_keys = self.keys != 0 || self.is_empty();
+        let show_metal = self.weapons != 0 || self.is_empty() || options.always_show_metal;
+        let keys = if options.abbreviate_keys {
+            format!("{}{}", self.keys, KEYS_ABBREVIATED_SYMBOL)
+        } else {
+            format!("{} {}", self.keys, helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL))
+        };
+        let metal = if let Some(decimals) = options.metal_decimals {
+            helpers::format_metal_fixed(self.weapons, decimals)
+        } else {
+            helpers::get_metal_float_from_weapons(self.weapons).to_string()
+        };
+        
+        match (show_keys, show_metal) {
+            (true, true) => format!("{keys}, {metal} {METAL_SYMBOL}"),
+            (true, false) => keys,
+            (false, _) => format!("{metal} {METAL_SYMBOL}"),
+        }
+    }
+    
+    /// Sums the currencies, stopping at the first item which causes the total to overflow or
+    /// underflow beyond the limit for [`Currency`].
+    /// 
+    /// # Errors
+    /// Errors with the index of the item which caused the overflow and the total of the items
+    /// before it.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency};
+    /// 
+    /// let items = [
+    ///     Currencies { keys: 1, weapons: 0 },
+    ///     Currencies { keys: Currency::MAX, weapons: 0 },
+    /// ];
+    /// 
+    /// assert_eq!(
+    ///     Currencies::checked_add_many(&items),
+    ///     Err((1, Currencies { keys: 1, weapons: 0 })),
+    /// );
+    /// ```
+    pub fn checked_add_many(items: &[Currencies]) -> Result<Self, (usize, Self)> {
+        items
+            .iter()
+            .enumerate()
+            .try_fold(Self::default(), |total, (index, currencies)| {
+                total.checked_add(*currencies).ok_or((index, total))
+            })
+    }
+    
+    /// Formats the currencies as a string with metal always displayed with 2 decimal places e.g.
+    /// `"2 keys, 23.00 ref"`. This is useful for aligning values in tables. Same as
+    /// [`Currencies::format_with`] using [`DisplayOptions::metal_decimals`] with `2`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 2,
+    ///     weapons: refined!(23),
+    /// };
+    /// 
+    /// assert_eq!(currencies.to_string_fixed_decimals(), "2 keys, 23.00 ref");
+    /// ```
+    pub fn to_string_fixed_decimals(&self) -> String {
+        self.format_with(&DisplayOptions::new().metal_decimals(2))
+    }
+    
+    /// Parses currencies from a string containing only metal e.g. `"5 ref"`.
+    /// 
+    /// # Errors
+    /// Errors if the string fails to parse or contains keys.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert_eq!(
+    ///     Currencies::parse_metal_only("5 ref").unwrap(),
+    ///     Currencies { keys: 0, weapons: refined!(5) },
+    /// );
+    /// assert!(Currencies::parse_metal_only("2 keys").is_err());
+    /// ```
+    pub fn parse_metal_only(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            keys: 0,
+            weapons: helpers::parse_metal_only_from_string(string)?,
+        })
+    }
+    
+    /// Parses currencies from a string containing only keys e.g. `"2 keys"`.
+    /// 
+    /// # Errors
+    /// Errors if the string fails to parse or contains metal.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// assert_eq!(
+    ///     Currencies::parse_keys_only("2 keys").unwrap(),
+    ///     Currencies { keys: 2, weapons: 0 },
+    /// );
+    /// assert!(Currencies::parse_keys_only("5 ref").is_err());
+    /// ```
+    pub fn parse_keys_only(string: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            keys: helpers::parse_keys_only_from_string(string)?,
+            weapons: 0,
+        })
+    }
+    
+    /// Converts currencies to a weapon value using the given [`KeyPrice`]. See
+    /// [`Currencies::to_weapons`].
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    pub fn to_weapons_key_price(&self, key_price: KeyPrice) -> Currency {
+        self.to_weapons(key_price.weapons())
+    }
+    
+    /// Converts a weapon value into the appropriate number of keys and weapons using the given
+    /// [`KeyPrice`]. See [`Currencies::from_weapons`].
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    pub fn from_weapons_key_price(weapons: Currency, key_price: KeyPrice) -> Self {
+        Self::from_weapons(weapons, key_price.weapons())
+    }
+    
+    /// Neatens currencies using the given [`KeyPrice`]. See [`Currencies::neaten`].
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    pub fn neaten_key_price(&self, key_price: KeyPrice) -> Self {
+        self.neaten(key_price.weapons())
+    }
+    
+    /// Neatens currencies only when the magnitude of the metal exceeds `threshold_keys` worth of
+    /// keys using the given key price (represented as weapons). Smaller amounts of metal are left
+    /// as metal, which keeps displayed values stable as metal accumulates.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(80),
+    /// };
+    /// 
+    /// // Less than 2 keys worth of metal.
+    /// assert_eq!(currencies.fold_excess_metal(key_price_weapons, 2), currencies);
+    /// assert_eq!(
+    ///     currencies.fold_excess_metal(key_price_weapons, 1),
+    ///     Currencies { keys: 2, weapons: refined!(30) },
+    /// );
+    /// ```
+    pub fn fold_excess_metal(&self, key_price_weapons: Currency, threshold_keys: Currency) -> Self {
+        let threshold_weapons = threshold_keys.saturating_mul(key_price_weapons);
+        
+        if self.weapons.saturating_abs() > threshold_weapons {
+            self.neaten(key_price_weapons)
+        } else {
+            *self
+        }
+    }
+    
+    /// Splits currencies into `n` parts which sum back to the original exactly. Keys and weapons
+    /// are each divided evenly, with any remainder distributed one at a time to the first parts.
+    /// The parts are produced lazily, so a large `n` does not allocate. The iterator is empty if
+    /// `n` is not positive.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 5,
+    ///     weapons: refined!(10) + 2,
+    /// };
+    /// let parts = currencies.split(3).collect::<Vec<_>>();
+    /// 
+    /// assert_eq!(parts, vec![
+    ///     Currencies { keys: 2, weapons: refined!(3) + 7 },
+    ///     Currencies { keys: 2, weapons: refined!(3) + 7 },
+    ///     Currencies { keys: 1, weapons: refined!(3) + 6 },
+    /// ]);
+    /// assert_eq!(parts.into_iter().sum::<Currencies>(), currencies);
+    /// ```
+    pub fn split(&self, n: Currency) -> impl Iterator<Item = Currencies> {
+        let currencies = *self;
+        // Gets the value of the part at the given index, with the remainder going to the first
+        // parts. The remainder has the same sign as the value being divided.
+        let part = move |value: Currency, index: Currency| {
+            let remainder = value % n;
+            
+            if index < remainder.abs() {
+                value / n + remainder.signum()
+            } else {
+                value / n
+            }
+        };
+        
+        // The range is empty if n is not positive, so part is never called with a zero divisor.
+        (0..n.max(0))
+            .map(move |index| Currencies {
+                keys: part(currencies.keys, index),
+                weapons: part(currencies.weapons, index),
+            })
+    }
+    
+    /// Applies a percentage to each field of the currencies, rounding to the nearest integer e.g.
+    /// `90.0` for a 10% discount or `110.0` for a 10% markup. The percentage is taken to four
+    /// decimal places and applied using integer arithmetic, so `100.0` leaves the currencies
+    /// unchanged. Unlike [`Currencies::apply_percent_value`], keys and weapons are scaled
+    /// independently.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 10,
+    ///     weapons: refined!(20),
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.apply_percent(90.0),
+    ///     Currencies { keys: 9, weapons: refined!(18) },
+    /// );
+    /// ```
+    pub fn apply_percent(&self, percent: f32) -> Self {
+        let scale = |value: Currency| {
+            helpers::saturating_i128_to_currency(helpers::percent_of_i128(value, percent))
+        };
+        
+        Self {
+            keys: scale(self.keys),
+            weapons: scale(self.weapons),
+        }
+    }
+    
+    /// Applies a percentage to each field of the currencies, rounding to the nearest integer. See
+    /// [`Currencies::apply_percent`]. `None` if the percentage is not finite or the result is out
+    /// of bounds for [`Currency`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Currency};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: Currency::MAX,
+    ///     weapons: 0,
+    /// };
+    /// 
+    /// assert_eq!(currencies.checked_apply_percent(110.0), None);
+    /// ```
+    pub fn checked_apply_percent(&self, percent: f32) -> Option<Self> {
+        if !percent.is_finite() {
+            return None;
+        }
+        
+        let scale = |value: Currency| {
+            Currency::try_from(helpers::percent_of_i128(value, percent)).ok()
+        };
+        
+        Some(Self {
+            keys: scale(self.keys)?,
+            weapons: scale(self.weapons)?,
+        })
+    }
+    
+    /// Applies a percentage to the total value of the currencies using the given key price
+    /// (represented as weapons), then converts the result back into keys and weapons. This avoids
+    /// the rounding error of scaling keys and weapons independently. Same as
+    /// [`Currencies::scale_value`] with `percent / 100.0`.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: 0,
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.apply_percent_value(90.0, key_price_weapons),
+    ///     Currencies { keys: 0, weapons: refined!(45) },
+    /// );
+    /// ```
+    pub fn apply_percent_value(&self, percent: f32, key_price_weapons: Currency) -> Self {
+        self.scale_value(percent / 100.0, key_price_weapons)
+    }
+    
+    /// Scales each field of the currencies by the given number of basis points, where `10_000`
+    /// is 100% e.g. `11_500` for a 115% scale. Each field is computed exactly as
+    /// `field * bps / 10_000` using integer arithmetic, truncating towards zero. Unlike
+    /// [`Currencies::scale_value`], keys and weapons are scaled independently.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 20,
+    ///     weapons: refined!(20),
+    /// };
+    /// 
+    /// assert_eq!(
+    ///     currencies.scale_bps(11_500),
+    ///     Currencies { keys: 23, weapons: refined!(23) },
+    /// );
+    /// ```
+    pub fn scale_bps(&self, bps: i64) -> Self {
+        let scale = |value: Currency| {
+            helpers::saturating_i128_to_currency(value as i128 * bps as i128 / 10_000)
+        };
+        
+        Self {
+            keys: scale(self.keys),
+            weapons: scale(self.weapons),
+        }
+    }
+    
+    /// Formats the value of these currencies as a single refined figure using the given key price
+    /// (represented as weapons) e.g. `"86.5 ref"`. If the key price is zero, only the metal
+    /// portion is shown.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, reclaimed};
+    /// 
+    /// let key_price_weapons = refined!(50);
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(36) + reclaimed!(1) + 3,
+    /// };
+    /// 
+    /// assert_eq!(currencies.to_refined_string(key_price_weapons), "86.5 ref");
+    /// ```
+    pub fn to_refined_string(&self, key_price_weapons: Currency) -> String {
+        format!("{} {METAL_SYMBOL}", self.to_refined_f32(key_price_weapons))
+    }
+    
+    /// Formats only the keys of these currencies e.g. `"2 keys"`. Zero keys are formatted as
+    /// `"0 keys"`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(5),
+    /// };
+    /// 
+    /// assert_eq!(currencies.display_keys(), "1 key");
+    /// ```
+    pub fn display_keys(&self) -> String {
+        format!(
+            "{} {}",
+            self.keys,
+            helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
+        )
+    }
+    
+    /// Formats only the metal of these currencies e.g. `"23.44 ref"`. Zero metal is formatted as
+    /// `"0 ref"`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let currencies = Currencies {
+    ///     keys: 1,
+    ///     weapons: refined!(23) + scrap!(4),
+    /// };
+    /// 
+    /// assert_eq!(currencies.display_metal(), "23.44 ref");
+    /// ```
+    pub fn display_metal(&self) -> String {
+        format!(
+            "{} {METAL_SYMBOL}",
+            helpers::get_metal_float_from_weapons(self.weapons),
+        )
+    }
+    
+    /// Parses currencies from a string using the given [`ParseOptions`]. Parsing with the default
+    /// options is the same as parsing using the [`FromStr`](std::str::FromStr) implementation.
+    /// 
+    /// # Errors
+    /// Errors if the string fails to parse or contains more components than allowed.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, ParseOptions, refined};
+    /// 
+    /// let options = ParseOptions::new().max_components(2);
+    /// 
+    /// assert_eq!(
+    ///     Currencies::parse_with("2 keys, 5 ref", &options).unwrap(),
+    ///     Currencies { keys: 2, weapons: refined!(5) },
+    /// );
+    /// ```
+    pub fn parse_with(string: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let (
+            keys,
+            weapons,
+        ) = helpers::parse_currency_from_string(string, options.max_components)?;
+        
+        Ok(Self {
+            keys,
+            weapons,
+        })
+    }
+}
+
+/// Comparison with [`FloatCurrencies`] will fail if [`FloatCurrencies`] has a fractional key 
+/// value.
+impl PartialEq<FloatCurrencies> for Currencies {
+    fn eq(&self, other: &FloatCurrencies) -> bool {
+        if let Some(weapons) = helpers::checked_get_weapons_from_metal_float(other.metal) {
+            other.keys.fract() != 0.0 &&
+            self.keys == other.keys as Currency &&
+            self.weapons == weapons
+        } else {
+            false
+        }
+    }
+}
+
+impl_op_ex!(+ |a: &Currencies, b: &Currencies| -> Currencies { 
+    Currencies {
+        keys: a.keys.saturating_add(b.keys),
+        weapons: a.weapons.saturating_add(b.weapons),
+    } 
+});
+
+impl_op_ex!(- |a: &Currencies, b: &Currencies| -> Currencies { 
+    Currencies {
+        keys: a.keys.saturating_sub(b.keys),
+        weapons: a.weapons.saturating_sub(b.weapons),
+    }
+});
+
+impl_op_ex!(- |a: &Currencies| -> Currencies {
+    Currencies {
+        keys: a.keys.saturating_neg(),
+        weapons: a.weapons.saturating_neg(),
+    }
+});
+
+impl_op_ex!(* |currencies: &Currencies, num: Currency| -> Currencies {
+    Currencies {
+        keys: currencies.keys.saturating_mul(num),
+        weapons: currencies.weapons.saturating_mul(num),
+    }
+});
+
+impl_op_ex!(/ |currencies: &Currencies, num: Currency| -> Currencies {
+    Currencies {
+        keys: currencies.keys.saturating_div(num),
+        weapons: currencies.weapons.saturating_div(num),
+    }
+});
+
+impl_op_ex!(* |currencies: &Currencies, num: f32| -> Currencies {
+    Currencies { 
+        keys: (currencies.keys as f32 * num).round() as Currency,
+        weapons: (currencies.weapons as f32 * num).round() as Currency,
+    }
+});
+
+impl_op_ex!(/ |currencies: &Currencies, num: f32| -> Currencies {
+    Currencies {
+        keys: (currencies.keys as f32 / num).round() as Currency,
+        weapons: (currencies.weapons as f32 / num).round() as Currency,
+    }
+});
+
+impl_op_ex!(+= |a: &mut Currencies, b: &Currencies| { 
+    a.keys = a.keys.saturating_add(b.keys);
+    a.weapons = a.weapons.saturating_add(b.weapons);
+});
+
+impl_op_ex!(-= |a: &mut Currencies, b: &Currencies| { 
+    a.keys = a.keys.saturating_sub(b.keys);
+    a.weapons = a.weapons.saturating_sub(b.weapons);
+});
+
+impl_op_ex!(*= |currencies: &mut Currencies, num: Currency| {
+    currencies.keys = currencies.keys.saturating_mul(num);
+    currencies.weapons = currencies.weapons.saturating_mul(num);
+});
+
+impl_op_ex!(/= |currencies: &mut Currencies, num: Currency| {
+    currencies.keys = currencies.keys.saturating_div(num);
+    currencies.weapons = currencies.weapons.saturating_div(num);
+});
+
+impl_op_ex!(*= |currencies: &mut Currencies, num: f32| {
+    currencies.keys = (currencies.keys as f32 * num).round() as Currency;
+    currencies.weapons = (currencies.weapons as f32 * num).round() as Currency;
+});
+
+impl_op_ex!(/= |currencies: &mut Currencies, num: f32| {
+    currencies.keys = (currencies.keys as f32 / num).round() as Currency;
+    currencies.weapons = (currencies.weapons as f32 / num).round() as Currency;
+});
+
+impl std::iter::Sum for Currencies {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::default(), |total, currencies| total + currencies)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Currencies> for Currencies {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::default(), |total, currencies| total + currencies)
+    }
+}
+
+impl FromIterator<Currencies> for Currencies {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        iter.into_iter().sum()
+    }
+}
+
+/// Collects `(price, quantity)` pairs into the quantity-weighted total of the prices. This sums
+/// each `price * quantity` rather than averaging them; use [`mean`](crate::mean) for an average.
+/// 
+/// This is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// 
+/// let listings = [
+///     (Currencies { keys: 1, weapons: 0 }, 2),
+///     (Currencies { keys: 0, weapons: refined!(5) }, 3),
+/// ];
+/// let total: Currencies = listings.into_iter().collect();
+/// 
+/// assert_eq!(total, Currencies { keys: 2, weapons: refined!(15) });
+/// ```
+impl FromIterator<(Currencies, u32)> for Currencies {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Self, u32)>,
+    {
+        iter.into_iter()
+            .map(|(currencies, quantity)| {
+                // A u32 may not fit into a 32-bit Currency.
+                currencies * quantity.min(Currency::MAX as u32) as Currency
+            })
+            .sum()
+    }
+}
+
+/// Iterates over each nonzero denomination in the currencies, breaking metal down into refined,
+/// reclaimed, scrap, and weapons using the fewest items possible.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, Denomination, refined, scrap};
+/// 
+/// let currencies = Currencies {
+///     keys: 2,
+///     weapons: refined!(23) + scrap!(1),
+/// };
+/// let denominations = currencies.into_iter().collect::<Vec<_>>();
+/// 
+/// assert_eq!(denominations, vec![
+///     (Denomination::Keys, 2),
+///     (Denomination::Refined, 23),
+///     (Denomination::Scrap, 1),
+/// ]);
+/// ```
+impl IntoIterator for Currencies {
+    type Item = (Denomination, Currency);
+    type IntoIter = std::iter::Flatten<std::array::IntoIter<Option<Self::Item>, 5>>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        [
+            (Denomination::Keys, self.keys),
+            (Denomination::Refined, helpers::refined_part(self.weapons)),
+            (Denomination::Reclaimed, helpers::reclaimed_part(self.weapons)),
+            (Denomination::Scrap, helpers::scrap_part(self.weapons)),
+            (Denomination::Weapon, helpers::weapon_part(self.weapons)),
+        ]
+            .map(|(denomination, count)| (count != 0).then_some((denomination, count)))
+            .into_iter()
+            .flatten()
+    }
+}
+
+impl TryFrom<&str> for Currencies {
+    type Error = ParseError;
+    
+    fn try_from(string: &str) -> Result<Self, Self::Error>  {
+        string.parse::<Self>()
+    }
+}
+
+impl TryFrom<&String> for Currencies {
+    type Error = ParseError;
+    
+    fn try_from(string: &String) -> Result<Self, Self::Error> {
+        string.parse::<Self>()
+    }
+}
+
+impl TryFrom<String> for Currencies {
+    type Error = ParseError;
+    
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        string.parse::<Self>()
+    }
+}
+
+impl std::str::FromStr for Currencies {
+    type Err = ParseError;
+    
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(string, &ParseOptions::default())
+    }
+}
+
+/// Converts [`FloatCurrencies`] to [`Currencies`].
+/// 
+/// # Errors
+/// - [`FloatCurrencies`] contains a fractional key value.
+/// - [`FloatCurrencies`] contains a value that is out of bounds.
+impl TryFrom<FloatCurrencies> for Currencies {
+    type Error = TryFromFloatCurrenciesError;
+    
+    fn try_from(currencies: FloatCurrencies) -> Result<Self, Self::Error> {
+        if currencies.keys.fract() != 0.0 {
+            return Err(TryFromFloatCurrenciesError::Fractional {
+                fract: currencies.keys.fract(),
+            });
+        }
+        
+        let keys = helpers::strict_f32_to_currency(currencies.keys)
+            .ok_or(TryFromFloatCurrenciesError::OutOfBounds {
+                value: currencies.keys,
+            })?;
+        let weapons = helpers::checked_get_weapons_from_metal_float(currencies.metal)
+            .ok_or(TryFromFloatCurrenciesError::OutOfBounds {
+                value: currencies.metal,
+            })?;
+        
+        Ok(Self {
+            keys,
+            weapons,
+        })
+    }
+}
+
+/// Converts [`FloatCurrencies`] to [`Currencies`].
+/// 
+/// # Errors
+/// - [`FloatCurrencies`] contains a fractional key value.
+/// - [`FloatCurrencies`] contains a value that is out of bounds.
+impl TryFrom<&FloatCurrencies> for Currencies {
+    type Error = TryFromFloatCurrenciesError;
+    
+    fn try_from(currencies: &FloatCurrencies) -> Result<Self, Self::Error> {
+        Self::try_from(*currencies)
+    }
+}
+
+impl fmt::Display for Currencies {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Either both keys and metal are non-zero or both are zero.
+        if (self.keys != 0 && self.weapons != 0) || self.is_empty() {
+            write!(
+                f,
+                "{} {}, {} {}",
+                self.keys,
+                helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
+                helpers::get_metal_float_from_weapons(self.weapons),
+                METAL_SYMBOL,
+            )
+        } else if self.keys != 0 {
+            write!(
+                f,
+                "{} {}",
+                self.keys,
+                helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
+            )
+        } else {
+            // It can be assumed that metal is not zero.
+            write!(
+                f,
+                "{} {}",
+                helpers::get_metal_float_from_weapons(self.weapons),
+                METAL_SYMBOL,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Currencies {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        
+        let currencies = Self::deserialize(deserializer)?;
+        
+        if currencies.keys == 0 && currencies.weapons == 0 {
+            return Err(D::Error::custom("Does not contain values for keys or metal"));
+        }
+        
+        Ok(currencies)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Currencies {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        
+        let mut currencies = serializer.serialize_struct("Currencies", 2)?;
+        
+        if self.keys == 0 {
+            currencies.skip_field("keys")?;
+        } else {
+            currencies.serialize_field("keys", &self.keys)?;
+        }
+        
+        if self.weapons == 0 {
+            currencies.skip_field("metal")?;
+        } else {
+            let float = helpers::get_metal_float_from_weapons(self.weapons);
+            
+            if float.fract() == 0.0 {
+                currencies.serialize_field("metal", &(float as Currency))?;
+            } else {
+                currencies.serialize_field("metal", &float)?;
+            }
+        }
+        
+        currencies.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, reclaimed, scrap};
+
+    #[test]
+    fn currencies_equal() {
+        assert_eq!(
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_not_equal() {
+        assert_ne!(
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+            Currencies {
+                keys: 2,
+                weapons: refined!(23),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_added() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } + Currencies {
+                keys: 5,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 15,
+                weapons: refined!(15),
+            },
+        );
+    }
+    
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn currencies_added_borrowed() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } + &Currencies {
+                keys: 5,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 15,
+                weapons: refined!(15),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_subtracted() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } - Currencies {
+                keys: 5,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 5,
+                weapons: refined!(5),
+            },
+        );
+    }
+    
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn currencies_subtracted_borrowed() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } - &Currencies {
+                keys: 5,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 5,
+                weapons: refined!(5),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_multiplied_by_currency() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } * 5,
+            Currencies {
+                keys: 50,
+                weapons: refined!(50),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_divided_by_f32() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } / 2.5,
+            Currencies {
+                keys: 4,
+                weapons: refined!(4),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_divided_by_currency() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } / 5,
+            Currencies {
+                keys: 2,
+                weapons: refined!(2),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_multiplied_by_f32() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: refined!(10),
+            } * 2.5,
+            Currencies {
+                keys: 25,
+                weapons: refined!(25),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_mul_assign_currency() {
+        let mut currencies = Currencies {
+            keys: 10,
+            weapons: refined!(10),
+        };
+        
+        currencies *= 2;
+        
+        assert_eq!(
+            currencies,
+            Currencies {
+                keys: 20,
+                weapons: refined!(20),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_mul_assign_f32() {
+        let mut currencies = Currencies {
+            keys: 10,
+            weapons: refined!(10),
+        };
+        
+        currencies *= 2.5;
+        
+        assert_eq!(
+            currencies,
+            Currencies {
+                keys: 25,
+                weapons: refined!(25),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_div_assign_currency() {
+        let mut currencies = Currencies {
+            keys: 10,
+            weapons: refined!(10),
+        };
+        
+        currencies /= 2;
+        
+        assert_eq!(
+            currencies,
+            Currencies {
+                keys: 5,
+                weapons: refined!(5),
+            },
+        );
+    }
+    
+    #[test]
+    fn currencies_div_assign_f32() {
+        let mut currencies = Currencies {
+            keys: 10,
+            weapons: refined!(10),
+        };
+        
+        currencies /= 2.5;
+        
+        assert_eq!(
+            currencies,
+            Currencies {
+                keys: 4,
+                weapons: refined!(4),
+            },
+        );
+    }
+    
+    #[test]
+    fn parses_currencies_from_string() {
+        let currencies = Currencies::try_from("2 keys, 23.44 ref").unwrap();
+        
+        assert_eq!(currencies.keys, 2);
+        assert_eq!(currencies.weapons, 422);
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_case_insensitive() {
+        let currencies = Currencies::try_from("2 KeYs, 23.44 ReF").unwrap();
+        
+        assert_eq!(currencies.keys, 2);
+        assert_eq!(currencies.weapons, 422);
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_only_keys() {
+        let currencies = Currencies::try_from("1 key").unwrap();
+        
+        assert_eq!(currencies.keys, 1);
+        assert_eq!(currencies.weapons, 0);
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_only_metal() {
+        let currencies = Currencies::try_from("2 ref").unwrap();
+        
+        assert_eq!(currencies.keys, 0);
+        assert_eq!(currencies.weapons, refined!(2));
+    }
+    
+    #[test]
+    fn parses_empty_currencies() {
+        let currencies = Currencies::try_from("0 keys, 0 ref").unwrap();
+        
+        assert_eq!(currencies.keys, 0);
+        assert_eq!(currencies.weapons, 0);
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_invalid_currencies() {
+        assert!(Currencies::try_from("what").is_err());
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_invalid_currencies_extra() {
+        assert!(Currencies::try_from("2 keys, 3 what").is_err());
+    }
+    
+    #[test]
+    fn prints_empty_currencies() {
+        assert_eq!(Currencies::default().to_string(), "0 keys, 0 ref");
+    }
+    
+    #[test]
+    fn prints_huge_currencies() {
+        assert_eq!(Currencies {
+            keys: 1000000,
+            weapons: 1000000,
+        }.to_string(), "1000000 keys, 55555.55 ref");
+    }
+    
+    #[test]
+    fn gets_correct_value_from_weapons() {
+        assert_eq!(
+            Currencies::from_weapons(9, 10),
+            Currencies {
+                keys: 0,
+                weapons: 9,
+            },
+        );
+    }
+    
+    #[test]
+    fn gets_correct_value_from_weapons_with_keys() {
+        assert_eq!(
+            Currencies::from_weapons(10, 10),
+            Currencies {
+                keys: 1,
+                weapons: 0,
+            },
+        );
+    }
+    
+    #[test]
+    fn gets_correct_value_from_weapons_with_keys_and_weapons() {
+        assert_eq!(
+            Currencies::from_weapons(11, 10),
+            Currencies {
+                keys: 1,
+                weapons: 1,
+            },
+        );
+    }
+    
+    #[test]
+    fn components() {
+        let currencies = Currencies {
+            keys: 3,
+            weapons: refined!(23) + reclaimed!(2) + scrap!(1) + 1,
+        };
+        
+        assert_eq!(
+            currencies.components(),
+            Components {
+                keys: 3,
+                weapons: refined!(23) + reclaimed!(2) + scrap!(1) + 1,
+                refined_float: 23.83,
+                refined: 23,
+                reclaimed: 2,
+                scrap: 1,
+                weapon: 1,
+            },
+        );
+    }
+    
+    #[test]
+    fn checked_from_weapons() {
+        let key_price_weapons = refined!(50);
+        let weapons = refined!(120) + scrap!(1);
+        
+        assert_eq!(
+            Currencies::checked_from_weapons(weapons, key_price_weapons),
+            Some(Currencies::from_weapons(weapons, key_price_weapons)),
+        );
+        assert_eq!(
+            Currencies::checked_from_weapons(-weapons, key_price_weapons),
+            Some(Currencies {
+                keys: -2,
+                weapons: -(refined!(20) + scrap!(1)),
+            }),
+        );
+    }
+    
+    #[test]
+    fn checked_from_weapons_invalid() {
+        assert_eq!(Currencies::checked_from_weapons(refined!(120), 0), None);
+        assert_eq!(Currencies::checked_from_weapons(0, 0), None);
+        assert_eq!(Currencies::checked_from_weapons(Currency::MIN, -1), None);
+    }
+    
+    #[test]
+    fn gets_correct_value_from_keys_f32() {
+        assert_eq!(
+            Currencies::from_keys_f32(1.5, 10),
+            Currencies {
+                keys: 1,
+                weapons: 5,
+            },
+        );
+    }
+    
+    #[test]
+    fn gets_correct_value_from_metal_f32() {
+        assert_eq!(
+            Currencies::from_metal_f32(1.33),
+            Currencies {
+                keys: 0,
+                weapons: refined!(1) + scrap!(3),
+            },
+        );
+        assert_eq!(Currencies::from_metal_f32(0.0), Currencies::default());
+        assert_eq!(Currencies::from_metal_f32(f32::NAN), Currencies::default());
+    }
+    
+    #[test]
+    fn checked_from_metal_f32() {
+        assert_eq!(
+            Currencies::checked_from_metal_f32(1.33),
+            Some(Currencies {
+                keys: 0,
+                weapons: refined!(1) + scrap!(3),
+            }),
+        );
+        assert_eq!(Currencies::checked_from_metal_f32(0.0), Some(Currencies::default()));
+        assert_eq!(Currencies::checked_from_metal_f32(f32::NAN), None);
+        assert_eq!(Currencies::checked_from_metal_f32(f32::INFINITY), None);
+        assert_eq!(Currencies::checked_from_metal_f32(f32::MAX), None);
+    }
+    
+    #[test]
+    fn formats_currencies() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(format!("{currencies}"), "2 keys, 23.44 ref");
+    }
+    
+    #[test]
+    fn formats_currencies_singular() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(format!("{currencies}"), "1 key, 23.44 ref");
+    }
+    
+    #[test]
+    fn formats_currencies_with_no_trailing_decimal_places() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23),
+        };
+        
+        assert_eq!(format!("{currencies}"), "2 keys, 23 ref");
+    }
+    
+    #[test]
+    fn formats_currencies_with_no_weapons() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: 0,
+        };
+        
+        assert_eq!(format!("{currencies}"), "2 keys");
+    }
+    
+    #[test]
+    fn converts_to_weapons() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4),
+        };
+        let value = currencies.to_weapons(422);
+        
+        assert_eq!(value, 844);
+    }
+    
+    #[test]
+    fn rounds_weapons_down() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4) + 1,
+        };
+        
+        assert_eq!(currencies.round(&Rounding::DownScrap).weapons, 422);
+    }
+    
+    #[test]
+    fn rounds_weapons_down_refined() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_up_refined_negative() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -refined!(23) + scrap!(1),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, -refined!(22));
+    }
+    
+    #[test]
+    fn rounds_weapons_up_refined_negative_whole_value() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -refined!(23),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, -refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_down_refined_negative() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -refined!(23) + scrap!(8),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, -refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_down_refined_negative_whole_value() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -refined!(23),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, -refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_down_refined_whole_value() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_up_refined() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, refined!(24));
+    }
+    
+    #[test]
+    fn rounds_weapons_up_refined_whole_value() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_refined_down_correctly() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(3),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_refined_down_correctly_whole_value() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_weapons_refined_up_correctly() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(5),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(24));
+    }
+    
+    #[test]
+    fn rounds_weapons_up() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4) + 1,
+        };
+        
+        assert_eq!(currencies.round(&Rounding::UpScrap).weapons, 424);
+    }
+    
+    #[test]
+    fn rounds_and_neatens() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(120) + scrap!(5),
+        };
+        
+        for rounding in [
+            Rounding::UpScrap,
+            Rounding::Reclaimed,
+            Rounding::DownReclaimed,
+            Rounding::UpRefined,
+            Rounding::DownRefined,
+            Rounding::None,
+        ] {
+            assert_eq!(
+                currencies.round_and_neaten(&rounding, key_price_weapons),
+                currencies.round(&rounding).neaten(key_price_weapons),
+            );
+        }
+        
+        assert_eq!(
+            currencies.round_and_neaten(&Rounding::UpRefined, key_price_weapons),
+            Currencies {
+                keys: 3,
+                weapons: refined!(21),
+            },
+        );
+    }
+    
+    #[test]
+    fn rounds_and_neatens_zero_key_price() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(120) + scrap!(5),
+        };
+        
+        assert_eq!(
+            currencies.round_and_neaten(&Rounding::UpRefined, 0),
+            Currencies {
+                keys: 1,
+                weapons: refined!(121),
+            },
+        );
+    }
+    
+    #[test]
+    fn rounds_weapons_in_place() {
+        let mut currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4) + 1,
+        };
+        
+        for (rounding, expected) in [
+            (Rounding::UpScrap, refined!(23) + scrap!(5)),
+            (Rounding::UpReclaimed, refined!(23) + reclaimed!(2)),
+            (Rounding::None, refined!(23) + reclaimed!(2)),
+            (Rounding::DownRefined, refined!(23)),
+        ] {
+            currencies.round_mut(&rounding);
+            
+            assert_eq!(currencies, Currencies { keys: 1, weapons: expected });
+        }
+    }
+    
+    #[test]
+    fn neatens() {
+        let currenices = Currencies {
+            keys: 1,
+            weapons: refined!(110),
+        };
+        
+        assert_eq!(
+            currenices.neaten(refined!(50)),
+            Currencies {
+                keys: 3,
+                weapons: refined!(10),
+            },
+        );
+    }
+    
+    #[test]
+    fn neatens_negative() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -refined!(110),
+        };
+        
+        assert_eq!(
+            currencies.neaten(refined!(50)),
+            Currencies {
+                keys: -1,
+                weapons: -refined!(10),
+            },
+        );
+    }
+    
+    #[test]
+    fn neatens_negative_result_should_be_positive() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: -refined!(60),
+        };
+        
+        assert_eq!(
+            currencies.neaten(refined!(50)),
+            Currencies {
+                keys: 0,
+                weapons: refined!(40),
+            },
+        );
+    }
+    
+    #[test]
+    fn normalizes_negative_weapons() {
+        let key_price_weapons = refined!(50);
+        
+        for (currencies, expected) in [
+            (
+                Currencies { keys: 2, weapons: -refined!(60) },
+                Currencies { keys: 0, weapons: refined!(40) },
+            ),
+            (
+                Currencies { keys: 1, weapons: -1 },
+                Currencies { keys: 0, weapons: key_price_weapons - 1 },
+            ),
+            (
+                Currencies { keys: 3, weapons: -refined!(100) },
+                Currencies { keys: 1, weapons: 0 },
+            ),
+            (
+                Currencies { keys: 5, weapons: -refined!(101) },
+                Currencies { keys: 2, weapons: refined!(49) },
+            ),
+            (
+                Currencies { keys: 1, weapons: refined!(60) },
+                Currencies { keys: 2, weapons: refined!(10) },
+            ),
+        ] {
+            let normalized = currencies.normalize(key_price_weapons);
+            
+            assert_eq!(normalized, expected);
+            assert_eq!(normalized, currencies.neaten(key_price_weapons));
+            assert!((0..key_price_weapons).contains(&normalized.weapons));
+        }
+    }
+    
+    #[test]
+    fn normalizes_negative_total() {
+        let key_price_weapons = refined!(50);
+        
+        assert_eq!(
+            Currencies {
+                keys: -2,
+                weapons: refined!(60),
+            }.normalize(key_price_weapons),
+            Currencies {
+                keys: 0,
+                weapons: -refined!(40),
+            },
+        );
+        assert_eq!(
+            Currencies {
+                keys: 0,
+                weapons: -refined!(60),
+            }.normalize(key_price_weapons),
+            Currencies {
+                keys: -1,
+                weapons: -refined!(10),
+            },
+        );
+    }
+    
+    #[test]
+    fn normalizes_without_collapsing_keys() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: -1,
+        };
+        
+        assert_eq!(
+            currencies.normalize(key_price_weapons),
+            Currencies {
+                keys: Currency::MAX - 1,
+                weapons: key_price_weapons - 1,
+            },
+        );
+    }
+    
+    #[test]
+    fn to_weapons_with_negative_keys() {
+        let key_price_weapons = refined!(10);
+        let currencies = Currencies {
+            keys: -10,
+            // 2 keys of metal, so the total should be -8 keys
+            weapons: key_price_weapons * 2,
+        };
+        
+        assert_eq!(currencies.to_weapons(key_price_weapons), -(key_price_weapons * 8));
+    }
+    
+    #[test]
+    fn greater_than() {
+        let a = Currencies { keys: 1, weapons: 5 };
+        let b = Currencies { keys: 0, weapons: 10 };
+        
+        assert!(a > b);
+    }
+    
+    #[test]
+    fn less_than() {
+        let a = Currencies { keys: 0, weapons: 1 };
+        let b = Currencies { keys: 0, weapons: 4 };
+        
+        assert!(a < b);
+    }
+    
+    #[test]
+    #[allow(clippy::useless_vec, clippy::manual_next_back)]
+    fn sorts() {
+        let mut currencies = vec![
+            Currencies { keys: 2, weapons: 4 },
+            Currencies { keys: 0, weapons: 2 },
+            Currencies { keys: 10, weapons: 4 },
+        ];
+        
+        // lowest to highest
+        currencies.sort();
+        
+        assert_eq!(
+            *currencies.iter().rev().next().unwrap(),
+            Currencies { keys: 10, weapons: 4 },
+        );
+    }
+    
+    #[test]
+    fn to_weapons_saturating_integer_bounds() {
+        let key_price_weapons = refined!(50);
+        
+        assert_eq!(
+            Currencies {
+                keys: Currency::MAX - 100,
+                weapons: 0,
+            }.to_weapons(key_price_weapons),
+            Currency::MAX,
+        );
+        assert_eq!(
+            Currencies {
+                keys: Currency::MAX - 100,
+                weapons: 0,
+            }.to_weapons(-key_price_weapons),
+            Currency::MIN,
+        );
+        assert_eq!(
+            Currencies {
+                keys: 1,
+                weapons: Currency::MAX,
+            }.to_weapons(key_price_weapons),
+            Currency::MAX,
+        );
+        assert_eq!(
+            Currencies {
+                keys: -1,
+                weapons: Currency::MIN,
+            }.to_weapons(key_price_weapons),
+            Currency::MIN,
+        );
+        assert_eq!(
+            Currencies {
+                keys: 1,
+                weapons: Currency::MIN,
+            }.to_weapons(key_price_weapons),
+            Currency::MIN + key_price_weapons,
+        );
+    }
+    
+    #[test]
+    fn checked_mul() {
+        assert_eq!(
+            Currencies {
+                keys: 2,
+                weapons: 0,
+            }.checked_mul(Currency::MAX),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_add() {
+        assert_eq!(
+            Currencies {
+                keys: 2,
+                weapons: 0,
+            }.checked_add(Currencies {
+                keys: Currency::MAX,
+                weapons: 0,
+            }),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_to_weapons() {
+        assert_eq!(
+            Currencies {
+                keys: Currency::MAX,
+                weapons: 0,
+            }.checked_to_weapons(Currency::MAX),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_to_weapons_correct_value() {
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: 5,
+            }.checked_to_weapons(10),
+            Some(105),
+        );
+    }
+    
+    #[test]
+    fn to_weapons_policy_saturates() {
+        assert_eq!(
+            Currencies {
+                keys: Currency::MAX,
+                weapons: 0,
+            }.to_weapons_policy(10, OverflowPolicy::Saturate),
+            Some(Currency::MAX),
+        );
+    }
+    
+    #[test]
+    fn to_weapons_policy_wraps() {
+        assert_eq!(
+            Currencies {
+                keys: Currency::MAX,
+                weapons: 0,
+            }.to_weapons_policy(2, OverflowPolicy::Wrap),
+            Some(-2),
+        );
+    }
+    
+    #[test]
+    fn to_weapons_policy_checked() {
+        assert_eq!(
+            Currencies {
+                keys: Currency::MAX,
+                weapons: 0,
+            }.to_weapons_policy(10, OverflowPolicy::Checked),
+            None,
+        );
+        assert_eq!(
+            Currencies {
+                keys: 10,
+                weapons: 5,
+            }.to_weapons_policy(10, OverflowPolicy::Checked),
+            Some(105),
+        );
+    }
+    
+    #[test]
+    fn from_float_currencies() {
+        let float_currencies = FloatCurrencies {
+            keys: 1.0,
+            metal: 1.33,
+        };
+        let currencies = Currencies::try_from(float_currencies).unwrap();
+        
+        assert_eq!(currencies.weapons, refined!(1) + scrap!(3));
+    }
+    
+    #[test]
+    fn from_float_currencies_infinity() {
+        assert!(Currencies::try_from(FloatCurrencies {
+            keys: f32::INFINITY,
+            metal: 1.33,
+        }).is_err());
+        assert!(Currencies::try_from(FloatCurrencies {
+            keys: f32::NEG_INFINITY,
+            metal: 1.33,
+        }).is_err());
+    }
+    
+    #[test]
+    fn from_float_currencies_nan() {
+        assert!(Currencies::try_from(FloatCurrencies {
+            keys: f32::NAN,
+            metal: 1.33,
+        }).is_err());
+        assert!(Currencies::try_from(FloatCurrencies {
+            keys: f32::NAN,
+            metal: 1.33,
+        }).is_err());
+    }
+    
+    #[test]
+    fn from_float_currencies_does_not_overflow_bounds() {
+        assert!(Currencies::try_from(FloatCurrencies {
+            keys: Currency::MAX as f32 * 2.0,
+            metal: 1.33,
+        }).is_err());
+    }
+    
+    #[test]
+    fn can_hash() {
+        let mut hash = std::collections::HashMap::<Currencies, i32>::new();
+        
+        hash.insert(Currencies {
+            keys: 1,
+            weapons: 1,
+        }, 1);
+        hash.insert(Currencies {
+            keys: 1,
+            weapons: 2,
+        }, 1);
+        
+        if let Some(value) = hash.get_mut(&Currencies {
+            keys: 1,
+            weapons: 1,
+        }) {
+            *value += 1;
+        }
+        
+        assert_eq!(
+            hash.get(&Currencies {
+                keys: 1,
+                weapons: 1,
+            }),
+            Some(&2),
+        );
+        assert_eq!(
+            hash.get(&Currencies {
+                keys: 1,
+                weapons: 2,
+            }),
+            Some(&1),
+        );
+    }
+    
+    #[test]
+    fn checked_div_by_exact() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 3,
+            weapons: 0,
+        };
+        let divisor = Currencies {
+            keys: 0,
+            weapons: refined!(25),
+        };
+        
+        assert_eq!(
+            currencies.checked_div_by(&divisor, key_price_weapons),
+            Some((6, Currencies::default())),
+        );
+    }
+    
+    #[test]
+    fn checked_div_by_with_remainder() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(10),
+        };
+        let divisor = Currencies {
+            keys: 1,
+            weapons: 0,
+        };
+        
+        assert_eq!(
+            currencies.checked_div_by(&divisor, key_price_weapons),
+            Some((2, Currencies {
+                keys: 0,
+                weapons: refined!(10),
+            })),
+        );
+    }
+    
+    #[test]
+    fn checked_div_by_zero() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(10),
+        };
+        
+        assert_eq!(currencies.checked_div_by(&Currencies::default(), refined!(50)), None);
+    }
+    
+    #[test]
+    fn checked_div_by_overflow() {
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: 0,
+        };
+        let divisor = Currencies {
+            keys: 1,
+            weapons: 0,
+        };
+        
+        assert_eq!(currencies.checked_div_by(&divisor, refined!(50)), None);
+    }
+    
+    #[test]
+    fn checked_div_by_zero_key_price() {
+        let currencies = Currencies {
+            keys: 0,
+            weapons: refined!(10),
+        };
+        let divisor = Currencies {
+            keys: 0,
+            weapons: refined!(3),
+        };
+        
+        assert_eq!(currencies.checked_div_by(&divisor, 0), None);
+    }
+    
+    #[test]
+    fn formats_delta_positive() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(11),
+        };
+        
+        assert_eq!(currencies.format_delta(), "+2 keys, +11 ref");
+    }
+    
+    #[test]
+    fn formats_delta_negative() {
+        let currencies = Currencies {
+            keys: 0,
+            weapons: -refined!(5),
+        };
+        
+        assert_eq!(currencies.format_delta(), "-5 ref");
+    }
+    
+    #[test]
+    fn formats_delta_mixed_sign() {
+        let currencies = Currencies {
+            keys: -1,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(currencies.format_delta(), "-1 key, +23.44 ref");
+    }
+    
+    #[test]
+    fn formats_delta_empty() {
+        assert_eq!(Currencies::default().format_delta(), "±0");
+    }
+    
+    #[test]
+    fn from_floats_saturating() {
+        assert_eq!(
+            Currencies::from_floats_saturating(2.0, 23.44, refined!(50)),
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+    }
+    
+    #[test]
+    fn from_floats_saturating_nan() {
+        assert_eq!(
+            Currencies::from_floats_saturating(f32::NAN, f32::NAN, refined!(50)),
+            Currencies::default(),
+        );
+        assert_eq!(
+            Currencies::from_floats_saturating(2.0, f32::NAN, refined!(50)),
+            Currencies {
+                keys: 2,
+                weapons: 0,
+            },
+        );
+    }
+    
+    #[test]
+    fn from_floats_saturating_out_of_range() {
+        assert_eq!(
+            Currencies::from_floats_saturating(f32::INFINITY, -1e30, refined!(50)),
+            Currencies {
+                keys: Currency::MAX,
+                weapons: Currency::MIN,
+            },
+        );
+    }
+    
+    #[test]
+    fn empty_is_neither_positive_nor_negative_value() {
+        let currencies = Currencies::default();
+        
+        assert!(!currencies.is_positive_value(refined!(50)));
+        assert!(!currencies.is_negative_value(refined!(50)));
+    }
+    
+    #[test]
+    fn is_positive_value() {
+        let currencies = Currencies {
+            keys: 0,
+            weapons: scrap!(1),
+        };
+        
+        assert!(currencies.is_positive_value(refined!(50)));
+        assert!(!currencies.is_negative_value(refined!(50)));
+    }
+    
+    #[test]
+    fn is_negative_value_with_positive_keys() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -refined!(60),
+        };
+        
+        assert!(currencies.is_negative_value(refined!(50)));
+        assert!(!currencies.is_positive_value(refined!(50)));
+        // The same currencies are positive at a higher key price.
+        assert!(currencies.is_positive_value(refined!(70)));
+    }
+    
+    #[test]
+    fn snaps_to_nice_refined_below_key() {
+        let currencies = Currencies {
+            keys: 0,
+            weapons: refined!(23) + scrap!(5),
+        };
+        
+        assert_eq!(
+            currencies.snap_to_nice(refined!(50)),
+            Currencies {
+                keys: 0,
+                weapons: refined!(24),
+            },
+        );
+    }
+    
+    #[test]
+    fn snaps_to_nice_half_keys() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(36),
+        };
+        
+        // 1.72 keys
+        assert_eq!(
+            currencies.snap_to_nice(refined!(50)),
+            Currencies {
+                keys: 1,
+                weapons: refined!(25),
+            },
+        );
+        
+        let currencies = Currencies {
+            keys: 3,
+            weapons: refined!(40),
+        };
+        
+        // 3.8 keys
+        assert_eq!(
+            currencies.snap_to_nice(refined!(50)),
+            Currencies {
+                keys: 4,
+                weapons: 0,
+            },
+        );
+    }
+    
+    #[test]
+    fn snaps_to_nice_whole_keys() {
+        let currencies = Currencies {
+            keys: 12,
+            weapons: refined!(30),
+        };
+        
+        assert_eq!(
+            currencies.snap_to_nice(refined!(50)),
+            Currencies {
+                keys: 13,
+                weapons: 0,
+            },
+        );
+    }
+    
+    #[test]
+    fn snaps_to_nice_negative() {
+        let currencies = Currencies {
+            keys: 0,
+            weapons: -(refined!(23) + scrap!(4)),
+        };
+        
+        assert_eq!(
+            currencies.snap_to_nice(refined!(50)),
+            Currencies {
+                keys: 0,
+                weapons: -refined!(23),
+            },
+        );
+    }
+    
+    #[test]
+    fn shortfall() {
+        let currencies = Currencies {
+            keys: 10,
+            weapons: refined!(30),
+        };
+        
+        assert_eq!(
+            currencies.shortfall(&Currencies {
+                keys: 10,
+                weapons: refined!(30),
+            }),
+            None,
+        );
+        assert_eq!(
+            currencies.shortfall(&Currencies {
+                keys: 5,
+                weapons: refined!(30) + 1,
+            }),
+            Some((false, true)),
+        );
+        assert_eq!(
+            currencies.shortfall(&Currencies {
+                keys: 11,
+                weapons: 0,
+            }),
+            Some((true, false)),
+        );
+        assert_eq!(
+            currencies.shortfall(&Currencies {
+                keys: 11,
+                weapons: refined!(31),
+            }),
+            Some((true, true)),
+        );
+    }
+    
+    #[test]
+    fn diff_keys_increase_weapons_decrease() {
+        let currencies = Currencies {
+            keys: 5,
+            weapons: refined!(2),
+        };
+        let other = Currencies {
+            keys: 2,
+            weapons: refined!(30) + scrap!(1),
+        };
+        
+        assert_eq!(currencies.diff(&other), (3, -(refined!(28) + scrap!(1))));
+        assert_eq!(other.diff(&currencies), (-3, refined!(28) + scrap!(1)));
+    }
+    
+    #[test]
+    #[allow(clippy::useless_conversion)]
+    fn diff_widens_or_saturates_values() {
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: Currency::MIN,
+        };
+        let other = Currencies {
+            keys: -1,
+            weapons: 1,
+        };
+        
+        assert_eq!(
+            currencies.diff(&other),
+            (
+                i64::from(Currency::MAX).saturating_add(1),
+                i64::from(Currency::MIN).saturating_sub(1),
+            ),
+        );
+    }
+    
+    #[test]
+    fn to_weapons_f64_is_more_precise_than_f32() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2_000_000,
+            weapons: 7,
+        };
+        let exact = 1_800_000_007_f64;
+        let weapons_f32 = currencies.to_weapons(key_price_weapons) as f32;
+        let weapons_f64 = currencies.to_weapons_f64(key_price_weapons);
+        
+        assert_eq!(weapons_f64, exact);
+        assert!((weapons_f64 - exact).abs() < (weapons_f32 as f64 - exact).abs());
+    }
+    
+    #[test]
+    fn to_weapons_f64_beyond_integer_bounds() {
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: 0,
+        };
+        
+        assert_eq!(currencies.to_weapons_f64(2), Currency::MAX as f64 * 2.0);
+    }
+    
+    #[test]
+    fn checked_neg() {
+        assert_eq!(
+            Currencies {
+                keys: 2,
+                weapons: -refined!(5),
+            }.checked_neg(),
+            Some(Currencies {
+                keys: -2,
+                weapons: refined!(5),
+            }),
+        );
+    }
+    
+    #[test]
+    fn checked_neg_min() {
+        assert_eq!(
+            Currencies {
+                keys: Currency::MIN,
+                weapons: 0,
+            }.checked_neg(),
+            None,
+        );
+        assert_eq!(
+            Currencies {
+                keys: 1,
+                weapons: Currency::MIN,
+            }.checked_neg(),
+            None,
+        );
+    }
+    
+    #[test]
+    fn abs() {
+        assert_eq!(
+            Currencies {
+                keys: -2,
+                weapons: refined!(5),
+            }.abs(),
+            Currencies {
+                keys: 2,
+                weapons: refined!(5),
+            },
+        );
+    }
+    
+    #[test]
+    fn abs_min() {
+        assert_eq!(
+            Currencies {
+                keys: Currency::MIN,
+                weapons: Currency::MIN,
+            }.abs(),
+            Currencies {
+                keys: Currency::MAX,
+                weapons: Currency::MAX,
+            },
+        );
+    }
+    
+    #[test]
+    fn sign_is_determined_by_keys_first() {
+        let mixed = Currencies {
+            keys: 1,
+            weapons: -refined!(60),
+        };
+        
+        assert!(mixed.is_positive());
+        assert!(!mixed.is_negative());
+        assert!((-mixed).is_negative());
+        assert!(Currencies { keys: 0, weapons: -1 }.is_negative());
+        assert!(!Currencies::new().is_positive());
+        assert!(!Currencies::new().is_negative());
+    }
+    
+    #[test]
+    fn cmp_by_value_then_fields() {
+        let key_price_weapons = refined!(50);
+        let key = Currencies {
+            keys: 1,
+            weapons: 0,
+        };
+        let metal = Currencies {
+            keys: 0,
+            weapons: refined!(50),
+        };
+        let cheaper = Currencies {
+            keys: 0,
+            weapons: refined!(49),
+        };
+        
+        assert_eq!(key.cmp_by_value_then_fields(&metal, key_price_weapons), Ordering::Greater);
+        assert_eq!(metal.cmp_by_value_then_fields(&key, key_price_weapons), Ordering::Less);
+        assert_eq!(key.cmp_by_value_then_fields(&key, key_price_weapons), Ordering::Equal);
+        assert_eq!(cheaper.cmp_by_value_then_fields(&metal, key_price_weapons), Ordering::Less);
+        
+        // The order is the same regardless of the original order.
+        let mut a = vec![key, cheaper, metal];
+        let mut b = vec![metal, key, cheaper];
+        
+        a.sort_by(|a, b| a.cmp_by_value_then_fields(b, key_price_weapons));
+        b.sort_by(|a, b| a.cmp_by_value_then_fields(b, key_price_weapons));
+        
+        assert_eq!(a, vec![cheaper, metal, key]);
+        assert_eq!(a, b);
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_and() {
+        let currencies = Currencies::try_from("2 keys and 3 ref").unwrap();
+        
+        assert_eq!(currencies.keys, 2);
+        assert_eq!(currencies.weapons, refined!(3));
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_comma_and() {
+        let currencies = Currencies::try_from("2 keys, and 3 ref").unwrap();
+        
+        assert_eq!(currencies.keys, 2);
+        assert_eq!(currencies.weapons, refined!(3));
+        
+        let currencies = Currencies::try_from("2 KEYS AND 23.44 REF").unwrap();
+        
+        assert_eq!(currencies.keys, 2);
+        assert_eq!(currencies.weapons, refined!(23) + scrap!(4));
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_and_inside_word() {
+        assert!(Currencies::try_from("2 keysand 3 ref").is_err());
+        assert!(Currencies::try_from("2 keys and").is_err());
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_leading_and() {
+        let error = Currencies::try_from("and 3 ref").unwrap_err();
+        
+        assert!(matches!(error, ParseError::MissingCount { span: Some((0, 0)) }));
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_doubled_and() {
+        let error = Currencies::try_from("2 keys and and 3 ref").unwrap_err();
+        
+        assert!(matches!(error, ParseError::MissingCount { span: Some((10, 11)) }));
+        assert!(Currencies::try_from("2 keys, and and 3 ref").is_err());
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_weapons() {
+        assert_eq!(
+            Currencies::try_from("1 key, 3 weapons").unwrap(),
+            Currencies {
+                keys: 1,
+                weapons: 3,
+            },
+        );
+        assert_eq!(
+            Currencies::try_from("1 weapon").unwrap(),
+            Currencies {
+                keys: 0,
+                weapons: 1,
+            },
+        );
+        assert!(Currencies::try_from("1.5 weapons").is_err());
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_ref_and_weapons() {
+        assert_eq!(
+            Currencies::try_from("2 keys, 23.44 ref, 1 weapon").unwrap(),
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4) + 1,
+            },
+        );
+        assert_eq!(
+            Currencies::parse_metal_only("1 ref and 3 weapons").unwrap(),
+            Currencies {
+                keys: 0,
+                weapons: refined!(1) + 3,
+            },
+        );
+        assert!(Currencies::parse_keys_only("2 keys, 3 weapons").is_err());
+    }
+    
+    #[test]
+    fn at_least_bumps_below_floor() {
+        let floor = Currencies {
+            keys: 0,
+            weapons: scrap!(1),
+        };
+        let currencies = Currencies {
+            keys: 0,
+            weapons: 1,
+        };
+        
+        assert_eq!(currencies.at_least(&floor, refined!(50)), floor);
+    }
+    
+    #[test]
+    fn at_least_above_floor_unchanged() {
+        let floor = Currencies {
+            keys: 0,
+            weapons: refined!(10),
+        };
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -refined!(30),
+        };
+        
+        assert_eq!(currencies.at_least(&floor, refined!(50)), currencies);
+    }
+    
+    #[test]
+    fn total_items_keys_only() {
+        let currencies = Currencies {
+            keys: 5,
+            weapons: 0,
+        };
+        
+        assert_eq!(currencies.total_items(), 5);
+    }
+    
+    #[test]
+    fn total_items_metal_only() {
+        let currencies = Currencies {
+            keys: 0,
+            // 23 refined, 1 reclaimed, 1 scrap
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(currencies.total_items(), 25);
+    }
+    
+    #[test]
+    fn total_items_mixed() {
+        let currencies = Currencies {
+            keys: -2,
+            weapons: -(refined!(1) + reclaimed!(2) + scrap!(2) + 1),
+        };
+        
+        assert_eq!(currencies.total_items(), 8);
+    }
+    
+    #[test]
+    fn add_strict_keys_overflowing_keys() {
+        assert_eq!(
+            Currencies {
+                keys: Currency::MAX,
+                weapons: 0,
+            }.add_strict_keys(&Currencies {
+                keys: 1,
+                weapons: 0,
+            }),
+            None,
+        );
+    }
+    
+    #[test]
+    fn add_strict_keys_saturates_weapons() {
+        assert_eq!(
+            Currencies {
+                keys: 2,
+                weapons: Currency::MAX - 1,
+            }.add_strict_keys(&Currencies {
+                keys: 3,
+                weapons: refined!(5),
+            }),
+            Some(Currencies {
+                keys: 5,
+                weapons: Currency::MAX,
+            }),
+        );
+    }
+    
+    #[test]
+    fn parses_special_free() {
+        assert_eq!(Currencies::parse_special("free").unwrap(), ParsedPrice::Free);
+        assert_eq!(Currencies::parse_special(" FREE ").unwrap(), ParsedPrice::Free);
+    }
+    
+    #[test]
+    fn parses_special_offer() {
+        assert_eq!(Currencies::parse_special("offer").unwrap(), ParsedPrice::Offer);
+    }
+    
+    #[test]
+    fn parses_special_value() {
+        assert_eq!(
+            Currencies::parse_special("2 keys").unwrap(),
+            ParsedPrice::Value(Currencies {
+                keys: 2,
+                weapons: 0,
+            }),
+        );
+        assert_eq!(
+            Currencies::parse_special("0 keys, 0 ref").unwrap(),
+            ParsedPrice::Value(Currencies::default()),
+        );
+        assert!(Currencies::parse_special("best offer").is_err());
+    }
+    
+    #[test]
+    fn round_band_brackets_value() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4) + 1,
+        };
+        let value = currencies.to_weapons(key_price_weapons);
+        
+        for rounding in [
+            Rounding::UpScrap,
+            Rounding::DownScrap,
+            Rounding::Reclaimed,
+            Rounding::UpReclaimed,
+            Rounding::DownReclaimed,
+            Rounding::Refined,
+            Rounding::UpRefined,
+            Rounding::DownRefined,
+            Rounding::None,
+        ] {
+            let (down, up) = currencies.round_band(&rounding);
+            
+            assert!(down.to_weapons(key_price_weapons) <= value);
+            assert!(up.to_weapons(key_price_weapons) >= value);
+        }
+    }
+    
+    #[test]
+    fn round_band_scrap() {
+        let currencies = Currencies {
+            keys: 0,
+            weapons: -(scrap!(3) + 1),
+        };
+        
+        assert_eq!(
+            currencies.round_band(&Rounding::UpScrap),
+            (
+                Currencies {
+                    keys: 0,
+                    weapons: -scrap!(4),
+                },
+                Currencies {
+                    keys: 0,
+                    weapons: -scrap!(3),
+                },
+            ),
+        );
+    }
+    
+    #[test]
+    fn to_weapons_avg_price() {
+        let currencies = Currencies {
+            keys: 3,
+            weapons: refined!(2) + scrap!(1),
+        };
+        let key_prices = [refined!(48), refined!(50) + 1, refined!(55) + 2];
+        let mean = (refined!(48) + refined!(50) + 1 + refined!(55) + 2) / 3;
+        
+        assert_eq!(
+            currencies.to_weapons_avg_price(&key_prices),
+            Some(currencies.to_weapons(mean)),
+        );
+    }
+    
+    #[test]
+    fn to_weapons_avg_price_empty() {
+        assert_eq!(Currencies::default().to_weapons_avg_price(&[]), None);
+    }
+    
+    #[test]
+    fn nearest_in() {
+        let key_price_weapons = refined!(50);
+        let candidates = [
+            Currencies {
+                keys: 2,
+                weapons: 0,
+            },
+            Currencies {
+                keys: 1,
+                weapons: refined!(10),
+            },
+            Currencies {
+                keys: 0,
+                weapons: refined!(5),
+            },
+        ];
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(12),
+        };
+        
+        assert_eq!(
+            currencies.nearest_in(&candidates, key_price_weapons),
+            Some((1, refined!(2))),
+        );
+    }
+    
+    #[test]
+    fn nearest_in_tie() {
+        let key_price_weapons = refined!(50);
+        let candidates = [
+            Currencies {
+                keys: 0,
+                weapons: refined!(10),
+            },
+            Currencies {
+                keys: 0,
+                weapons: refined!(20),
+            },
+        ];
+        let currencies = Currencies {
+            keys: 0,
+            weapons: refined!(15),
+        };
+        
+        assert_eq!(
+            currencies.nearest_in(&candidates, key_price_weapons),
+            Some((0, refined!(5))),
+        );
+    }
+    
+    #[test]
+    fn nearest_in_empty() {
+        assert_eq!(Currencies::default().nearest_in(&[], refined!(50)), None);
+    }
+    
+    #[test]
+    fn scale_value_carries_into_keys() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(40) + scrap!(1),
+        };
+        
+        assert_eq!(
+            currencies.scale_value(2.0, key_price_weapons),
+            Currencies {
+                keys: 5,
+                weapons: refined!(30) + scrap!(2),
+            },
+        );
+        assert_eq!(
+            currencies * 2.0,
+            Currencies {
+                keys: 4,
+                weapons: refined!(80) + scrap!(2),
+            },
+        );
+    }
+    
+    #[test]
+    fn scale_value_saturates() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: Currency::MAX / key_price_weapons,
+            weapons: 0,
+        };
+        
+        assert_eq!(
+            currencies.scale_value(4.0, key_price_weapons),
+            Currencies::from_weapons(Currency::MAX, key_price_weapons),
+        );
+    }
+    
+    #[test]
+    fn from_refined_hundredths() {
+        assert_eq!(
+            Currencies::from_refined_hundredths(0, 2344),
+            Currencies {
+                keys: 0,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+        assert_eq!(
+            Currencies::from_refined_hundredths(1, -2311),
+            Currencies {
+                keys: 1,
+                weapons: -(refined!(23) + scrap!(1)),
+            },
+        );
+        assert_eq!(
+            Currencies::from_refined_hundredths(0, 5),
+            Currencies {
+                keys: 0,
+                weapons: 1,
+            },
+        );
+    }
+    
+    #[test]
+    fn from_refined_hundredths_does_not_overflow() {
+        let hundredths = Currency::MAX - Currency::MAX % 100;
+        
+        assert_eq!(
+            Currencies::from_refined_hundredths(0, hundredths),
+            Currencies {
+                keys: 0,
+                weapons: hundredths / 100 * ONE_REF,
+            },
+        );
+    }
+    
+    #[test]
+    fn checked_to_weapons_capped() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 10,
+            weapons: refined!(5),
+        };
+        
+        assert_eq!(
+            currencies.checked_to_weapons_capped(key_price_weapons, 1000),
+            Some(refined!(505)),
+        );
+    }
+    
+    #[test]
+    fn checked_to_weapons_capped_overflows() {
+        let currencies = Currencies {
+            keys: 1000,
+            weapons: 0,
+        };
+        
+        assert_eq!(currencies.checked_to_weapons_capped(Currency::MAX, 1000), None);
     }
-}
-
-impl_op_ex!(+ |a: &Currencies, b: &Currencies| -> Currencies { 
-    Currencies {
-        keys: a.keys.saturating_add(b.keys),
-        weapons: a.weapons.saturating_add(b.weapons),
-    } 
-});
-
-impl_op_ex!(- |a: &Currencies, b: &Currencies| -> Currencies { 
-    Currencies {
-        keys: a.keys.saturating_sub(b.keys),
-        weapons: a.weapons.saturating_sub(b.weapons),
+    
+    #[test]
+    fn checked_to_weapons_capped_exceeds_max_keys() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: -1001,
+            weapons: 0,
+        };
+        
+        assert_eq!(currencies.checked_to_weapons_capped(key_price_weapons, 1000), None);
+        assert_eq!(
+            currencies.checked_to_weapons_capped(key_price_weapons, 1001),
+            Some(-refined!(50050)),
+        );
     }
-});
-
-impl_op_ex!(* |currencies: &Currencies, num: Currency| -> Currencies {
-    Currencies {
-        keys: currencies.keys.saturating_mul(num),
-        weapons: currencies.weapons.saturating_mul(num),
+    
+    #[test]
+    fn checked_to_weapons_batch() {
+        let key_price_weapons = refined!(50);
+        let items = [
+            Currencies {
+                keys: 2,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: Currency::MAX,
+                weapons: 0,
+            },
+            Currencies {
+                keys: 0,
+                weapons: Currency::MIN,
+            },
+            Currencies {
+                keys: -1,
+                weapons: Currency::MIN,
+            },
+        ];
+        // Stale values in the buffer are cleared.
+        let mut out = vec![Some(1); 8];
+        
+        Currencies::checked_to_weapons_batch(&items, key_price_weapons, &mut out);
+        
+        assert_eq!(out, [Some(refined!(105)), None, Some(Currency::MIN), None]);
     }
-});
-
-impl_op_ex!(/ |currencies: &Currencies, num: Currency| -> Currencies {
-    Currencies {
-        keys: currencies.keys.saturating_div(num),
-        weapons: currencies.weapons.saturating_div(num),
+    
+    #[test]
+    fn dual_display() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(25),
+        };
+        
+        assert_eq!(currencies.dual_display(key_price_weapons), "1.5 keys (75 ref)");
+        assert_eq!(
+            Currencies {
+                keys: 1,
+                weapons: 0,
+            }.dual_display(key_price_weapons),
+            "1 key (50 ref)",
+        );
     }
-});
-
-impl_op_ex!(* |currencies: &Currencies, num: f32| -> Currencies {
-    Currencies { 
-        keys: (currencies.keys as f32 * num).round() as Currency,
-        weapons: (currencies.weapons as f32 * num).round() as Currency,
+    
+    #[test]
+    fn dual_display_zero_key_price() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(currencies.dual_display(0), "23.44 ref");
     }
-});
-
-impl_op_ex!(/ |currencies: &Currencies, num: f32| -> Currencies {
-    Currencies {
-        keys: (currencies.keys as f32 / num).round() as Currency,
-        weapons: (currencies.weapons as f32 / num).round() as Currency,
+    
+    #[test]
+    fn to_weapons_checked_detailed() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(5),
+        };
+        
+        assert_eq!(
+            currencies.to_weapons_checked_detailed(key_price_weapons),
+            Ok(refined!(105)),
+        );
     }
-});
-
-impl_op_ex!(+= |a: &mut Currencies, b: &Currencies| { 
-    a.keys = a.keys.saturating_add(b.keys);
-    a.weapons = a.weapons.saturating_add(b.weapons);
-});
-
-impl_op_ex!(-= |a: &mut Currencies, b: &Currencies| { 
-    a.keys = a.keys.saturating_sub(b.keys);
-    a.weapons = a.weapons.saturating_sub(b.weapons);
-});
-
-impl_op_ex!(*= |currencies: &mut Currencies, num: Currency| {
-    currencies.keys = currencies.keys.saturating_mul(num);
-    currencies.weapons = currencies.weapons.saturating_mul(num);
-});
-
-impl_op_ex!(/= |currencies: &mut Currencies, num: Currency| {
-    currencies.keys = currencies.keys.saturating_div(num);
-    currencies.weapons = currencies.weapons.saturating_div(num);
-});
-
-impl_op_ex!(*= |currencies: &mut Currencies, num: f32| {
-    currencies.keys = (currencies.keys as f32 * num).round() as Currency;
-    currencies.weapons = (currencies.weapons as f32 * num).round() as Currency;
-});
-
-impl_op_ex!(/= |currencies: &mut Currencies, num: f32| {
-    currencies.keys = (currencies.keys as f32 / num).round() as Currency;
-    currencies.weapons = (currencies.weapons as f32 / num).round() as Currency;
-});
-
-impl TryFrom<&str> for Currencies {
-    type Error = ParseError;
     
-    fn try_from(string: &str) -> Result<Self, Self::Error>  {
-        string.parse::<Self>()
+    #[test]
+    fn to_weapons_checked_detailed_overflows() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: Currency::MIN,
+            weapons: -1,
+        };
+        
+        assert_eq!(
+            currencies.to_weapons_checked_detailed(key_price_weapons),
+            Err(Currency::MIN as i128 * key_price_weapons as i128 - 1),
+        );
     }
-}
-
-impl TryFrom<&String> for Currencies {
-    type Error = ParseError;
     
-    fn try_from(string: &String) -> Result<Self, Self::Error> {
-        string.parse::<Self>()
+    #[test]
+    fn from_weapons_signed() {
+        let key_price_weapons = refined!(50);
+        
+        assert_eq!(
+            Currencies::from_weapons_signed(refined!(60), key_price_weapons),
+            (
+                Currencies {
+                    keys: 1,
+                    weapons: refined!(10),
+                },
+                1,
+            ),
+        );
+        assert_eq!(
+            Currencies::from_weapons_signed(-refined!(10), key_price_weapons),
+            (
+                Currencies {
+                    keys: 0,
+                    weapons: -refined!(10),
+                },
+                -1,
+            ),
+        );
+        assert_eq!(
+            Currencies::from_weapons_signed(0, key_price_weapons),
+            (Currencies::default(), 0),
+        );
     }
-}
-
-impl TryFrom<String> for Currencies {
-    type Error = ParseError;
     
-    fn try_from(string: String) -> Result<Self, Self::Error> {
-        string.parse::<Self>()
+    #[test]
+    fn compound_zero_periods() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(currencies.compound(1.5, 0, key_price_weapons), currencies);
+    }
+    
+    #[test]
+    fn compound_zero_periods_is_unchanged() {
+        let key_price_weapons = refined!(50);
+        let unneatened = Currencies {
+            keys: 0,
+            weapons: refined!(60),
+        };
+        let large = Currencies {
+            keys: Currency::MAX - 1,
+            weapons: 0,
+        };
+        
+        assert_eq!(unneatened.compound(1.5, 0, key_price_weapons), unneatened);
+        assert_eq!(large.compound(1.5, 0, key_price_weapons), large);
+    }
+    
+    #[test]
+    fn compound_one_period() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(
+            currencies.compound(1.5, 1, key_price_weapons),
+            currencies.scale_value(1.5, key_price_weapons),
+        );
+    }
+    
+    #[test]
+    fn compound_saturates() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 1,
+            weapons: 0,
+        };
+        
+        assert_eq!(
+            currencies.compound(2.0, 1000, key_price_weapons),
+            Currencies::from_weapons(Currency::MAX, key_price_weapons),
+        );
+    }
+    
+    #[test]
+    fn parse_error_span_invalid_currency_name() {
+        let error = Currencies::try_from("2 keys, 3 what").unwrap_err();
+        
+        assert!(matches!(error, ParseError::InvalidCurrencyName { span: Some((10, 14)), .. }));
+        assert_eq!(&"2 keys, 3 what"[10..14], "what");
+    }
+    
+    #[test]
+    fn parse_error_span_unexpected_token() {
+        let error = Currencies::try_from("2 keys, 3 ref extra").unwrap_err();
+        
+        assert_eq!(error.span(), Some((14, 19)));
+        assert!(matches!(error, ParseError::UnexpectedToken { .. }));
+    }
+    
+    #[test]
+    fn parse_error_contains_token() {
+        let error = Currencies::try_from("2 keys, 3 reff").unwrap_err();
+        
+        assert!(matches!(&error, ParseError::InvalidCurrencyName { token, .. } if token == "reff"));
+        assert_eq!(error.to_string(), r#"Invalid currency name: "reff""#);
+        
+        let error = Currencies::try_from("2 keys, 3 ref extra").unwrap_err();
+        
+        assert!(matches!(&error, ParseError::UnexpectedToken { token, .. } if token == "extra"));
+        assert!(error.to_string().contains("extra"));
+    }
+    
+    #[test]
+    fn parse_error_span_trailing_comma() {
+        let error = Currencies::try_from("2 keys, ").unwrap_err();
+        
+        assert!(matches!(error, ParseError::MissingCount { span: Some((7, 8)) }));
+    }
+    
+    #[test]
+    fn parse_error_span_empty_element() {
+        let error = Currencies::try_from("2 keys,, 3 ref").unwrap_err();
+        
+        assert!(matches!(error, ParseError::MissingCount { span: Some((7, 7)) }));
+    }
+    
+    #[test]
+    fn parse_error_span_none() {
+        assert_eq!(Currencies::try_from("").unwrap_err().span(), None);
+    }
+    
+    #[test]
+    fn into_iter_denominations() {
+        let currencies = Currencies {
+            keys: -1,
+            weapons: -(refined!(3) + reclaimed!(2) + 1),
+        };
+        
+        assert_eq!(
+            currencies.into_iter().collect::<Vec<_>>(),
+            vec![
+                (Denomination::Keys, -1),
+                (Denomination::Refined, -3),
+                (Denomination::Reclaimed, -2),
+                (Denomination::Weapon, -1),
+            ],
+        );
+    }
+    
+    #[test]
+    fn into_iter_empty() {
+        assert_eq!(Currencies::default().into_iter().next(), None);
+    }
+    
+    #[test]
+    fn to_string_rounded() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(5) + scrap!(5),
+        };
+        
+        assert_eq!(currencies.to_string_rounded(&Rounding::Refined), "1 key, 6 ref");
+        assert_eq!(currencies.to_string_rounded(&Rounding::None), "1 key, 5.55 ref");
+        assert_eq!(
+            currencies,
+            Currencies {
+                keys: 1,
+                weapons: refined!(5) + scrap!(5),
+            },
+        );
+    }
+    
+    #[test]
+    fn checked_neaten() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: -1,
+            weapons: -refined!(75),
+        };
+        
+        assert_eq!(
+            currencies.checked_neaten(key_price_weapons),
+            Some(Currencies {
+                keys: -2,
+                weapons: -refined!(25),
+            }),
+        );
     }
-}
-
-impl std::str::FromStr for Currencies {
-    type Err = ParseError;
     
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let (
-            keys,
-            weapons,
-        ) = helpers::parse_currency_from_string(string)?;
+    #[test]
+    fn checked_neaten_overflows() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: 0,
+        };
         
-        Ok(Self {
-            keys,
-            weapons,
-        })
+        assert_eq!(currencies.checked_neaten(key_price_weapons), None);
+        // The saturating variant silently clamps the value.
+        assert_eq!(
+            currencies.neaten(key_price_weapons),
+            Currencies::from_weapons(Currency::MAX, key_price_weapons),
+        );
     }
-}
-
-/// Converts [`FloatCurrencies`] to [`Currencies`].
-/// 
-/// # Errors
-/// - [`FloatCurrencies`] contains a fractional key value.
-/// - [`FloatCurrencies`] contains a value that is out of bounds.
-impl TryFrom<FloatCurrencies> for Currencies {
-    type Error = TryFromFloatCurrenciesError;
     
-    fn try_from(currencies: FloatCurrencies) -> Result<Self, Self::Error> {
-        if currencies.keys.fract() != 0.0 {
-            return Err(TryFromFloatCurrenciesError::Fractional {
-                fract: currencies.keys.fract(),
-            });
-        }
-        
-        let keys = helpers::strict_f32_to_currency(currencies.keys)
-            .ok_or(TryFromFloatCurrenciesError::OutOfBounds {
-                value: currencies.keys,
-            })?;
-        let weapons = helpers::checked_get_weapons_from_metal_float(currencies.metal)
-            .ok_or(TryFromFloatCurrenciesError::OutOfBounds {
-                value: currencies.metal,
-            })?;
+    #[test]
+    fn checked_neaten_zero_key_price() {
+        let currencies = Currencies {
+            keys: 0,
+            weapons: refined!(5),
+        };
         
-        Ok(Self {
-            keys,
-            weapons,
-        })
+        assert_eq!(currencies.checked_neaten(0), None);
     }
-}
-
-/// Converts [`FloatCurrencies`] to [`Currencies`].
-/// 
-/// # Errors
-/// - [`FloatCurrencies`] contains a fractional key value.
-/// - [`FloatCurrencies`] contains a value that is out of bounds.
-impl TryFrom<&FloatCurrencies> for Currencies {
-    type Error = TryFromFloatCurrenciesError;
     
-    fn try_from(currencies: &FloatCurrencies) -> Result<Self, Self::Error> {
-        Self::try_from(*currencies)
-    }
-}
-
-impl fmt::Display for Currencies {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // Either both keys and metal are non-zero or both are zero.
-        if (self.keys != 0 && self.weapons != 0) || self.is_empty() {
-            write!(
-                f,
-                "{} {}, {} {}",
-                self.keys,
-                helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
-                helpers::get_metal_float_from_weapons(self.weapons),
-                METAL_SYMBOL,
-            )
-        } else if self.keys != 0 {
-            write!(
-                f,
-                "{} {}",
-                self.keys,
-                helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
-            )
-        } else {
-            // It can be assumed that metal is not zero.
-            write!(
-                f,
-                "{} {}",
-                helpers::get_metal_float_from_weapons(self.weapons),
-                METAL_SYMBOL,
-            )
-        }
-    }
-}
-
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for Currencies {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        use serde::de::Error;
-        
-        let currencies = Self::deserialize(deserializer)?;
-        
-        if currencies.keys == 0 && currencies.weapons == 0 {
-            return Err(D::Error::custom("Does not contain values for keys or metal"));
-        }
+    #[test]
+    fn div_value_floor_exact() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: 0,
+        };
+        let divisor = Currencies {
+            keys: 0,
+            weapons: refined!(25),
+        };
         
-        Ok(currencies)
+        assert_eq!(currencies.div_value_floor(&divisor, key_price_weapons), 4);
     }
-}
-
-#[cfg(feature = "serde")]
-impl serde::Serialize for Currencies {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        use serde::ser::SerializeStruct;
+    
+    #[test]
+    fn div_value_floor_rounds_down() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(10),
+        };
+        let divisor = Currencies {
+            keys: 0,
+            weapons: refined!(25),
+        };
         
-        let mut currencies = serializer.serialize_struct("Currencies", 2)?;
+        assert_eq!(currencies.div_value_floor(&divisor, key_price_weapons), 2);
+        assert_eq!(
+            (Currencies::default() - currencies).div_value_floor(&divisor, key_price_weapons),
+            -3,
+        );
+    }
+    
+    #[test]
+    fn div_value_floor_zero_divisor() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 1,
+            weapons: 0,
+        };
         
-        if self.keys == 0 {
-            currencies.skip_field("keys")?;
-        } else {
-            currencies.serialize_field("keys", &self.keys)?;
-        }
+        assert_eq!(currencies.div_value_floor(&Currencies::default(), key_price_weapons), 0);
+    }
+    
+    #[test]
+    fn is_value_multiple_of() {
+        let key_price_weapons = refined!(50);
+        let unit = Currencies {
+            keys: 0,
+            weapons: refined!(20),
+        };
         
-        if self.weapons == 0 {
-            currencies.skip_field("metal")?;
-        } else {
-            let float = helpers::get_metal_float_from_weapons(self.weapons);
-            
-            if float.fract() == 0.0 {
-                currencies.serialize_field("metal", &(float as Currency))?;
-            } else {
-                currencies.serialize_field("metal", &float)?;
-            }
-        }
+        assert!((unit * 3).is_value_multiple_of(&unit, key_price_weapons));
+        assert!(Currencies {
+            keys: 1,
+            weapons: refined!(10),
+        }.is_value_multiple_of(&unit, key_price_weapons));
+        assert!(!Currencies {
+            keys: 1,
+            weapons: refined!(5),
+        }.is_value_multiple_of(&unit, key_price_weapons));
+    }
+    
+    #[test]
+    fn is_value_multiple_of_edge_cases() {
+        let key_price_weapons = refined!(50);
         
-        currencies.end()
+        assert!(!Currencies::default().is_value_multiple_of(&Currencies::default(), key_price_weapons));
+        assert!(Currencies {
+            keys: 0,
+            weapons: Currency::MIN,
+        }.is_value_multiple_of(&Currencies { keys: 0, weapons: -1 }, key_price_weapons));
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{refined, scrap};
-
+    
     #[test]
-    fn currencies_equal() {
-        assert_eq!(
+    fn sums_currencies() {
+        let prices = [
             Currencies {
-                keys: 2,
-                weapons: refined!(23) + scrap!(4),
+                keys: 1,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 0,
+                weapons: scrap!(3),
             },
             Currencies {
                 keys: 2,
-                weapons: refined!(23) + scrap!(4),
+                weapons: -refined!(1),
             },
-        );
+        ];
+        let expected = Currencies {
+            keys: 3,
+            weapons: refined!(4) + scrap!(3),
+        };
+        
+        assert_eq!(prices.iter().sum::<Currencies>(), expected);
+        assert_eq!(prices.into_iter().sum::<Currencies>(), expected);
     }
     
     #[test]
-    fn currencies_not_equal() {
-        assert_ne!(
+    fn sums_empty_currencies() {
+        let prices: [Currencies; 0] = [];
+        
+        assert_eq!(prices.iter().sum::<Currencies>(), Currencies::default());
+        assert_eq!(prices.into_iter().sum::<Currencies>(), Currencies::default());
+    }
+    
+    #[test]
+    fn sums_currencies_saturating() {
+        let prices = [
             Currencies {
-                keys: 2,
-                weapons: refined!(23) + scrap!(4),
+                keys: Currency::MAX,
+                weapons: 0,
             },
             Currencies {
-                keys: 2,
-                weapons: refined!(23),
+                keys: 1,
+                weapons: 0,
             },
-        );
+        ];
+        
+        assert_eq!(prices.iter().sum::<Currencies>().keys, Currency::MAX);
     }
     
     #[test]
-    fn currencies_added() {
+    fn negates_currencies() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: -refined!(5),
+        };
+        let negated = Currencies {
+            keys: -2,
+            weapons: refined!(5),
+        };
+        
+        assert_eq!(-currencies, negated);
+        assert_eq!(-&currencies, negated);
+        assert_eq!(-(-currencies), currencies);
+    }
+    
+    #[test]
+    fn negates_currencies_saturating() {
+        let currencies = Currencies {
+            keys: Currency::MIN,
+            weapons: Currency::MIN,
+        };
+        
         assert_eq!(
+            -currencies,
             Currencies {
-                keys: 10,
-                weapons: refined!(10),
-            } + Currencies {
-                keys: 5,
-                weapons: refined!(5),
+                keys: Currency::MAX,
+                weapons: Currency::MAX,
             },
+        );
+    }
+    
+    #[test]
+    fn sets_metal_from_float() {
+        let mut currencies = Currencies {
+            keys: 2,
+            weapons: refined!(5),
+        };
+        
+        currencies.set_metal_from_float(1.33);
+        
+        assert_eq!(
+            currencies,
             Currencies {
-                keys: 15,
-                weapons: refined!(15),
+                keys: 2,
+                weapons: refined!(1) + scrap!(3),
             },
         );
     }
     
     #[test]
-    fn currencies_added_borrowed() {
+    fn try_sets_metal_from_float() {
+        let mut currencies = Currencies {
+            keys: 2,
+            weapons: refined!(5),
+        };
+        
+        assert!(currencies.try_set_metal_from_float(1.33).is_ok());
+        assert_eq!(currencies.weapons, refined!(1) + scrap!(3));
+    }
+    
+    #[test]
+    fn try_sets_metal_from_float_rejects_invalid() {
+        let mut currencies = Currencies {
+            keys: 2,
+            weapons: refined!(5),
+        };
+        
+        assert!(matches!(
+            currencies.try_set_metal_from_float(f32::NAN),
+            Err(TryFromFloatCurrenciesError::OutOfBounds { .. }),
+        ));
+        assert!(currencies.try_set_metal_from_float(f32::INFINITY).is_err());
+        assert!(currencies.try_set_metal_from_float(f32::MAX).is_err());
+        assert_eq!(currencies.weapons, refined!(5));
+    }
+    
+    #[test]
+    fn ensures_tradeable() {
         assert_eq!(
+            Currencies::default().ensure_tradeable(),
             Currencies {
-                keys: 10,
-                weapons: refined!(10),
-            } + &Currencies {
-                keys: 5,
-                weapons: refined!(5),
-            },
-            Currencies {
-                keys: 15,
-                weapons: refined!(15),
+                keys: 0,
+                weapons: 1,
             },
         );
+        
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(5),
+        };
+        
+        assert_eq!(currencies.ensure_tradeable(), currencies);
     }
     
     #[test]
-    fn currencies_subtracted() {
+    fn parses_currencies_from_string_without_spaces() {
         assert_eq!(
+            Currencies::try_from("2keys").unwrap(),
             Currencies {
-                keys: 10,
-                weapons: refined!(10),
-            } - Currencies {
-                keys: 5,
-                weapons: refined!(5),
+                keys: 2,
+                weapons: 0,
             },
+        );
+        assert_eq!(
+            Currencies::try_from("23.44ref").unwrap(),
             Currencies {
-                keys: 5,
-                weapons: refined!(5),
+                keys: 0,
+                weapons: refined!(23) + scrap!(4),
             },
         );
-    }
-    
-    #[test]
-    fn currencies_subtracted_borrowed() {
         assert_eq!(
+            Currencies::try_from("2 keys,23.44ref").unwrap(),
             Currencies {
-                keys: 10,
-                weapons: refined!(10),
-            } - &Currencies {
-                keys: 5,
-                weapons: refined!(5),
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
             },
+        );
+        assert_eq!(
+            Currencies::try_from("1key, 23.44 ref").unwrap(),
             Currencies {
-                keys: 5,
-                weapons: refined!(5),
+                keys: 1,
+                weapons: refined!(23) + scrap!(4),
             },
         );
     }
     
     #[test]
-    fn currencies_multiplied_by_currency() {
-        assert_eq!(
-            Currencies {
-                keys: 10,
-                weapons: refined!(10),
-            } * 5,
-            Currencies {
-                keys: 50,
-                weapons: refined!(50),
-            },
-        );
+    fn parses_currencies_from_string_without_spaces_invalid() {
+        assert!(Currencies::try_from("2keys extra").is_err());
+        assert!(Currencies::try_from("2what").is_err());
     }
     
     #[test]
-    fn currencies_divided_by_f32() {
+    fn parses_currencies_from_string_rejects_scientific_notation() {
+        let error = Currencies::try_from("1e3 ref").unwrap_err();
+        
+        assert!(matches!(error, ParseError::ScientificNotation { span: Some((1, 3)) }));
+        assert!(matches!(
+            Currencies::try_from("2 keys, 1.5E-2ref"),
+            Err(ParseError::ScientificNotation { span: Some((11, 14)) }),
+        ));
+        assert!(matches!(
+            FloatCurrencies::try_from("1e3 keys"),
+            Err(ParseError::ScientificNotation { .. }),
+        ));
         assert_eq!(
+            Currencies::try_from("1000 ref").unwrap(),
             Currencies {
-                keys: 10,
-                weapons: refined!(10),
-            } / 2.5,
-            Currencies {
-                keys: 4,
-                weapons: refined!(4),
+                keys: 0,
+                weapons: refined!(1000),
             },
         );
     }
     
     #[test]
-    fn currencies_divided_by_currency() {
+    fn to_refined_f32() {
+        let key_price_weapons = refined!(50) + scrap!(3);
+        
         assert_eq!(
             Currencies {
-                keys: 10,
+                keys: 1,
                 weapons: refined!(10),
-            } / 5,
+            }.to_refined_f32(key_price_weapons),
+            60.33,
+        );
+        assert_eq!(
             Currencies {
-                keys: 2,
-                weapons: refined!(2),
-            },
+                keys: -1,
+                weapons: -scrap!(3),
+            }.to_refined_f32(key_price_weapons),
+            -50.66,
         );
+        assert_eq!(Currencies::default().to_refined_f32(key_price_weapons), 0.0);
     }
     
     #[test]
-    fn currencies_multiplied_by_f32() {
+    fn to_keys_f32() {
+        let key_price_weapons = refined!(60);
+        
         assert_eq!(
             Currencies {
-                keys: 10,
-                weapons: refined!(10),
-            } * 2.5,
+                keys: 1,
+                weapons: refined!(30),
+            }.to_keys_f32(key_price_weapons),
+            1.5,
+        );
+        assert_eq!(
             Currencies {
-                keys: 25,
-                weapons: refined!(25),
-            },
+                keys: 0,
+                weapons: refined!(15),
+            }.to_keys_f32(key_price_weapons),
+            0.25,
+        );
+        assert_eq!(
+            Currencies {
+                keys: -2,
+                weapons: 0,
+            }.to_keys_f32(key_price_weapons),
+            -2.0,
         );
     }
     
     #[test]
-    fn currencies_mul_assign_currency() {
-        let mut currencies = Currencies {
-            keys: 10,
-            weapons: refined!(10),
+    fn to_float_keys() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(12) + reclaimed!(1) + 3,
         };
         
-        currencies *= 2;
-        
         assert_eq!(
-            currencies,
-            Currencies {
-                keys: 20,
-                weapons: refined!(20),
+            currencies.to_float_keys(key_price_weapons),
+            FloatCurrencies {
+                keys: 2.25,
+                metal: 0.0,
             },
         );
     }
     
     #[test]
-    fn currencies_mul_assign_f32() {
-        let mut currencies = Currencies {
-            keys: 10,
-            weapons: refined!(10),
+    fn to_float_keys_zero_key_price() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23) + scrap!(4),
         };
         
-        currencies *= 2.5;
-        
         assert_eq!(
-            currencies,
-            Currencies {
-                keys: 25,
-                weapons: refined!(25),
+            currencies.to_float_keys(0),
+            FloatCurrencies {
+                keys: 2.0,
+                metal: 23.44,
             },
         );
     }
     
     #[test]
-    fn currencies_div_assign_currency() {
-        let mut currencies = Currencies {
-            keys: 10,
-            weapons: refined!(10),
-        };
+    fn from_weapons_pair_validated() {
+        let key_price_weapons = refined!(50);
         
-        currencies /= 2;
+        let currencies = Currencies::from_weapons_pair_validated(
+            (2, -refined!(49)),
+            key_price_weapons,
+        ).unwrap();
         
         assert_eq!(
             currencies,
             Currencies {
-                keys: 5,
-                weapons: refined!(5),
+                keys: 2,
+                weapons: -refined!(49),
             },
         );
     }
     
     #[test]
-    fn currencies_div_assign_f32() {
-        let mut currencies = Currencies {
-            keys: 10,
-            weapons: refined!(10),
-        };
-        
-        currencies /= 2.5;
+    fn from_weapons_pair_validated_not_neatened() {
+        let key_price_weapons = refined!(50);
         
-        assert_eq!(
-            currencies,
-            Currencies {
-                keys: 4,
-                weapons: refined!(4),
-            },
+        assert!(matches!(
+            Currencies::from_weapons_pair_validated((1, refined!(50)), key_price_weapons),
+            Err(PriceError::NotNeatened { weapons, .. }) if weapons == refined!(50),
+        ));
+        assert!(
+            Currencies::from_weapons_pair_validated((0, -refined!(60)), key_price_weapons).is_err()
         );
+        assert!(Currencies::from_weapons_pair_validated((0, 0), 0).is_err());
     }
     
     #[test]
-    fn parses_currencies_from_string() {
-        let currencies = Currencies::try_from("2 keys, 23.44 ref").unwrap();
+    fn rounds_weapons_reclaimed() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4),
+        };
         
-        assert_eq!(currencies.keys, 2);
-        assert_eq!(currencies.weapons, 422);
+        assert_eq!(currencies.round(&Rounding::Reclaimed).weapons, refined!(23) + reclaimed!(1));
     }
     
     #[test]
-    fn parses_currencies_from_string_case_insensitive() {
-        let currencies = Currencies::try_from("2 KeYs, 23.44 ReF").unwrap();
+    fn rounds_weapons_up_reclaimed() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4),
+        };
         
-        assert_eq!(currencies.keys, 2);
-        assert_eq!(currencies.weapons, 422);
+        assert_eq!(currencies.round(&Rounding::UpReclaimed).weapons, refined!(23) + reclaimed!(2));
     }
     
     #[test]
-    fn parses_currencies_from_string_only_keys() {
-        let currencies = Currencies::try_from("1 key").unwrap();
+    fn rounds_weapons_down_reclaimed() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(23) + scrap!(4),
+        };
         
-        assert_eq!(currencies.keys, 1);
-        assert_eq!(currencies.weapons, 0);
+        assert_eq!(
+            currencies.round(&Rounding::DownReclaimed).weapons,
+            refined!(23) + reclaimed!(1),
+        );
     }
     
     #[test]
-    fn parses_currencies_from_string_only_metal() {
-        let currencies = Currencies::try_from("2 ref").unwrap();
+    fn rounds_weapons_up_reclaimed_negative() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -(refined!(23) + scrap!(4)),
+        };
         
-        assert_eq!(currencies.keys, 0);
-        assert_eq!(currencies.weapons, refined!(2));
+        assert_eq!(
+            currencies.round(&Rounding::UpReclaimed).weapons,
+            -(refined!(23) + reclaimed!(1)),
+        );
     }
     
     #[test]
-    fn parses_empty_currencies() {
-        let currencies = Currencies::try_from("0 keys, 0 ref").unwrap();
+    fn rounds_weapons_down_reclaimed_negative() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -(refined!(23) + scrap!(4)),
+        };
         
-        assert_eq!(currencies.keys, 0);
-        assert_eq!(currencies.weapons, 0);
+        assert_eq!(
+            currencies.round(&Rounding::DownReclaimed).weapons,
+            -(refined!(23) + reclaimed!(2)),
+        );
     }
     
     #[test]
-    fn parses_currencies_from_string_invalid_currencies() {
-        assert!(Currencies::try_from("what").is_err());
+    fn rounds_weapons_reclaimed_negative_whole_value() {
+        let currencies = Currencies {
+            keys: 1,
+            weapons: -reclaimed!(5),
+        };
+        
+        assert_eq!(currencies.round(&Rounding::UpReclaimed).weapons, -reclaimed!(5));
+        assert_eq!(currencies.round(&Rounding::DownReclaimed).weapons, -reclaimed!(5));
     }
     
     #[test]
-    fn parses_currencies_from_string_invalid_currencies_extra() {
-        assert!(Currencies::try_from("2 keys, 3 what").is_err());
+    fn blends_currencies() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: 0,
+        };
+        let other = Currencies {
+            keys: 1,
+            weapons: refined!(10),
+        };
+        
+        assert_eq!(currencies.blend(&other, 1.0, key_price_weapons), currencies);
+        assert_eq!(currencies.blend(&other, 0.0, key_price_weapons), other);
+        assert_eq!(
+            currencies.blend(&other, 0.5, key_price_weapons),
+            Currencies {
+                keys: 1,
+                weapons: refined!(30),
+            },
+        );
     }
     
     #[test]
-    fn prints_empty_currencies() {
-        assert_eq!(Currencies::default().to_string(), "0 keys, 0 ref");
+    fn blends_currencies_clamps_weight() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 2,
+            weapons: 0,
+        };
+        let other = Currencies {
+            keys: 1,
+            weapons: 0,
+        };
+        
+        assert_eq!(currencies.blend(&other, 2.0, key_price_weapons), currencies);
+        assert_eq!(currencies.blend(&other, -1.0, key_price_weapons), other);
     }
     
     #[test]
-    fn prints_huge_currencies() {
-        assert_eq!(Currencies {
-            keys: 1000000,
-            weapons: 1000000,
-        }.to_string(), "1000000 keys, 55555.55 ref");
+    #[should_panic(expected = "key price must not be zero")]
+    #[cfg(debug_assertions)]
+    fn neaten_zero_key_price_panics() {
+        Currencies {
+            keys: 1,
+            weapons: refined!(5),
+        }.neaten(0);
     }
     
     #[test]
-    fn gets_correct_value_from_weapons() {
+    fn format_with_default_matches_display() {
+        for currencies in [
+            Currencies::default(),
+            Currencies {
+                keys: 1,
+                weapons: 0,
+            },
+            Currencies {
+                keys: 0,
+                weapons: refined!(23) + scrap!(4),
+            },
+            Currencies {
+                keys: -2,
+                weapons: -refined!(5),
+            },
+        ] {
+            assert_eq!(currencies.format_with(&DisplayOptions::default()), currencies.to_string());
+        }
+    }
+    
+    #[test]
+    fn format_with_metal_decimals() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23) + scrap!(4),
+        };
+        
+        assert_eq!(
+            currencies.format_with(&DisplayOptions::new().metal_decimals(0)),
+            "2 keys, 23 ref",
+        );
         assert_eq!(
-            Currencies::from_weapons(9, 10),
-            Currencies {
-                keys: 0,
-                weapons: 9,
-            },
+            currencies.format_with(&DisplayOptions::new().metal_decimals(1)),
+            "2 keys, 23.4 ref",
+        );
+        assert_eq!(
+            currencies.format_with(&DisplayOptions::new().metal_decimals(3)),
+            "2 keys, 23.444 ref",
         );
     }
     
     #[test]
-    fn gets_correct_value_from_weapons_with_keys() {
+    fn format_with_always_show_metal() {
+        let options = DisplayOptions::new().always_show_metal(true);
+        
         assert_eq!(
-            Currencies::from_weapons(10, 10),
             Currencies {
                 keys: 1,
                 weapons: 0,
-            },
+            }.format_with(&options),
+            "1 key, 0 ref",
         );
-    }
-    
-    #[test]
-    fn gets_correct_value_from_weapons_with_keys_and_weapons() {
         assert_eq!(
-            Currencies::from_weapons(11, 10),
             Currencies {
-                keys: 1,
-                weapons: 1,
-            },
+                keys: 0,
+                weapons: refined!(5),
+            }.format_with(&options),
+            "5 ref",
         );
     }
     
     #[test]
-    fn gets_correct_value_from_keys_f32() {
+    fn format_with_abbreviate_keys() {
+        let options = DisplayOptions::new().abbreviate_keys(true);
+        
         assert_eq!(
-            Currencies::from_keys_f32(1.5, 10),
             Currencies {
                 keys: 1,
-                weapons: 5,
-            },
+                weapons: refined!(5),
+            }.format_with(&options),
+            "1k, 5 ref",
         );
+        assert_eq!(Currencies::default().format_with(&options), "0k, 0 ref");
     }
     
     #[test]
-    fn formats_currencies() {
-        let currencies = Currencies {
-            keys: 2,
-            weapons: refined!(23) + scrap!(4),
-        };
+    fn format_with_all_options() {
+        let options = DisplayOptions::new()
+            .metal_decimals(2)
+            .always_show_metal(true)
+            .abbreviate_keys(true);
         
-        assert_eq!(format!("{currencies}"), "2 keys, 23.44 ref");
+        assert_eq!(
+            Currencies {
+                keys: 3,
+                weapons: 0,
+            }.format_with(&options),
+            "3k, 0.00 ref",
+        );
+        assert_eq!(
+            Currencies {
+                keys: 0,
+                weapons: -(refined!(1) + scrap!(3)),
+            }.format_with(&options),
+            "-1.33 ref",
+        );
     }
     
     #[test]
-    fn formats_currencies_singular() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23) + scrap!(4),
-        };
+    fn checked_add_many() {
+        let items = [
+            Currencies {
+                keys: 1,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 2,
+                weapons: scrap!(1),
+            },
+        ];
         
-        assert_eq!(format!("{currencies}"), "1 key, 23.44 ref");
+        assert_eq!(
+            Currencies::checked_add_many(&items),
+            Ok(Currencies {
+                keys: 3,
+                weapons: refined!(5) + scrap!(1),
+            }),
+        );
+        assert_eq!(Currencies::checked_add_many(&[]), Ok(Currencies::default()));
     }
     
     #[test]
-    fn formats_currencies_with_no_trailing_decimal_places() {
-        let currencies = Currencies {
-            keys: 2,
-            weapons: refined!(23),
-        };
+    fn checked_add_many_overflows() {
+        let items = [
+            Currencies {
+                keys: 1,
+                weapons: 0,
+            },
+            Currencies {
+                keys: 0,
+                weapons: Currency::MIN,
+            },
+            Currencies {
+                keys: 0,
+                weapons: -1,
+            },
+            Currencies {
+                keys: 5,
+                weapons: 0,
+            },
+        ];
         
-        assert_eq!(format!("{currencies}"), "2 keys, 23 ref");
+        assert_eq!(
+            Currencies::checked_add_many(&items),
+            Err((
+                2,
+                Currencies {
+                    keys: 1,
+                    weapons: Currency::MIN,
+                },
+            )),
+        );
     }
     
     #[test]
-    fn formats_currencies_with_no_weapons() {
-        let currencies = Currencies {
-            keys: 2,
-            weapons: 0,
-        };
+    fn collects_currencies() {
+        let prices = [
+            Currencies {
+                keys: 1,
+                weapons: refined!(5),
+            },
+            Currencies {
+                keys: 0,
+                weapons: -scrap!(3),
+            },
+            Currencies {
+                keys: 2,
+                weapons: refined!(1),
+            },
+        ];
+        let total: Currencies = prices
+            .into_iter()
+            .filter(|currencies| currencies.weapons > 0)
+            .collect();
         
-        assert_eq!(format!("{currencies}"), "2 keys");
+        assert_eq!(
+            total,
+            Currencies {
+                keys: 3,
+                weapons: refined!(6),
+            },
+        );
+        assert_eq!(std::iter::empty::<Currencies>().collect::<Currencies>(), Currencies::default());
     }
     
     #[test]
-    fn converts_to_weapons() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23) + scrap!(4),
-        };
-        let value = currencies.to_weapons(422);
+    fn collects_weighted_currencies() {
+        let listings = [
+            (Currencies {
+                keys: 1,
+                weapons: refined!(5),
+            }, 3),
+            (Currencies {
+                keys: 0,
+                weapons: scrap!(1),
+            }, 4),
+            (Currencies {
+                keys: 10,
+                weapons: 0,
+            }, 0),
+        ];
+        let total: Currencies = listings.into_iter().collect();
         
-        assert_eq!(value, 844);
+        assert_eq!(
+            total,
+            Currencies {
+                keys: 3,
+                weapons: refined!(15) + scrap!(4),
+            },
+        );
     }
     
     #[test]
-    fn rounds_weapons_down() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23) + scrap!(4) + 1,
-        };
+    fn collects_weighted_currencies_saturates() {
+        let total: Currencies = [(Currencies {
+            keys: Currency::MAX,
+            weapons: 1,
+        }, 2)].into_iter().collect();
         
-        assert_eq!(currencies.round(&Rounding::DownScrap).weapons, 422);
+        assert_eq!(
+            total,
+            Currencies {
+                keys: Currency::MAX,
+                weapons: 2,
+            },
+        );
     }
     
     #[test]
-    fn rounds_weapons_down_refined() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23) + scrap!(4),
-        };
-        
-        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, refined!(23));
+    fn saturating_add() {
+        assert_eq!(
+            Currencies {
+                keys: 2,
+                weapons: -1,
+            }.saturating_add(Currencies {
+                keys: Currency::MAX,
+                weapons: Currency::MIN,
+            }),
+            Currencies {
+                keys: Currency::MAX,
+                weapons: Currency::MIN,
+            },
+        );
     }
     
     #[test]
-    fn rounds_weapons_up_refined_negative() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: -refined!(23) + scrap!(1),
-        };
-        
-        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, -refined!(22));
+    fn saturating_sub() {
+        assert_eq!(
+            Currencies {
+                keys: -2,
+                weapons: 1,
+            }.saturating_sub(Currencies {
+                keys: Currency::MAX,
+                weapons: Currency::MIN,
+            }),
+            Currencies {
+                keys: Currency::MIN,
+                weapons: Currency::MAX,
+            },
+        );
     }
     
     #[test]
-    fn rounds_weapons_up_refined_negative_whole_value() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: -refined!(23),
-        };
-        
-        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, -refined!(23));
+    fn to_string_fixed_decimals() {
+        assert_eq!(
+            Currencies {
+                keys: 0,
+                weapons: refined!(23),
+            }.to_string_fixed_decimals(),
+            "23.00 ref",
+        );
+        assert_eq!(
+            Currencies {
+                keys: 0,
+                weapons: refined!(23) + scrap!(4),
+            }.to_string_fixed_decimals(),
+            "23.44 ref",
+        );
+        assert_eq!(
+            Currencies {
+                keys: 1,
+                weapons: 0,
+            }.to_string_fixed_decimals(),
+            "1 key",
+        );
+        assert_eq!(Currencies::default().to_string_fixed_decimals(), "0 keys, 0.00 ref");
     }
     
     #[test]
-    fn rounds_weapons_down_refined_negative() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: -refined!(23) + scrap!(8),
-        };
-        
-        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, -refined!(23));
+    fn parses_metal_only() {
+        assert_eq!(
+            Currencies::parse_metal_only("23.44 ref").unwrap(),
+            Currencies {
+                keys: 0,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+        assert!(matches!(
+            Currencies::parse_metal_only("2 keys"),
+            Err(ParseError::UnexpectedToken { span: Some((0, 1)), .. }),
+        ));
+        assert!(Currencies::parse_metal_only("2 keys, 5 ref").is_err());
+        assert!(Currencies::parse_metal_only("").is_err());
     }
     
     #[test]
-    fn rounds_weapons_down_refined_negative_whole_value() {
-        let currencies = Currencies {
-            keys: 1,
-            weapons: -refined!(23),
-        };
-        
-        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, -refined!(23));
+    fn parses_keys_only() {
+        assert_eq!(
+            Currencies::parse_keys_only("2 keys").unwrap(),
+            Currencies {
+                keys: 2,
+                weapons: 0,
+            },
+        );
+        assert!(matches!(
+            Currencies::parse_keys_only("2 keys, 5 ref"),
+            Err(ParseError::UnexpectedToken { span: Some((8, 9)), .. }),
+        ));
+        assert!(Currencies::parse_keys_only("1.5 keys").is_err());
     }
     
     #[test]
-    fn rounds_weapons_down_refined_whole_value() {
+    fn converts_with_key_price() {
+        let key_price = KeyPrice::from_refined(50.0);
         let currencies = Currencies {
             keys: 1,
-            weapons: refined!(23),
+            weapons: refined!(60),
         };
         
-        assert_eq!(currencies.round(&Rounding::DownRefined).weapons, refined!(23));
+        assert_eq!(currencies.to_weapons_key_price(key_price), refined!(110));
+        assert_eq!(
+            Currencies::from_weapons_key_price(refined!(110), key_price),
+            Currencies {
+                keys: 2,
+                weapons: refined!(10),
+            },
+        );
+        assert_eq!(currencies.neaten_key_price(key_price), currencies.neaten(refined!(50)));
     }
     
     #[test]
-    fn rounds_weapons_up_refined() {
+    fn fold_excess_metal_below_threshold() {
+        let key_price_weapons = refined!(50);
         let currencies = Currencies {
             keys: 1,
-            weapons: refined!(23) + scrap!(4),
+            weapons: refined!(100),
         };
         
-        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, refined!(24));
+        assert_eq!(currencies.fold_excess_metal(key_price_weapons, 2), currencies);
     }
     
     #[test]
-    fn rounds_weapons_up_refined_whole_value() {
+    fn fold_excess_metal_above_threshold() {
+        let key_price_weapons = refined!(50);
         let currencies = Currencies {
             keys: 1,
-            weapons: refined!(23),
+            weapons: refined!(100) + 1,
         };
         
-        assert_eq!(currencies.round(&Rounding::UpRefined).weapons, refined!(23));
+        assert_eq!(
+            currencies.fold_excess_metal(key_price_weapons, 2),
+            Currencies {
+                keys: 3,
+                weapons: 1,
+            },
+        );
+        assert_eq!(
+            Currencies {
+                keys: 0,
+                weapons: -refined!(120),
+            }.fold_excess_metal(key_price_weapons, 2),
+            Currencies {
+                keys: -2,
+                weapons: -refined!(20),
+            },
+        );
     }
     
     #[test]
-    fn rounds_weapons_refined_down_correctly() {
+    fn splits_currencies() {
         let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23) + scrap!(3),
+            keys: 7,
+            weapons: refined!(23) + scrap!(4) + 1,
         };
         
-        assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(23));
+        for n in 1..=10 {
+            let parts = currencies.split(n).collect::<Vec<_>>();
+            
+            assert_eq!(parts.len() as Currency, n);
+            assert_eq!(parts.iter().sum::<Currencies>(), currencies);
+            
+            // Parts differ by at most one key and one weapon.
+            let max = parts.iter().max().unwrap();
+            let min = parts.iter().min().unwrap();
+            
+            assert!(max.keys - min.keys <= 1);
+            assert!(parts.iter().all(|part| (part.weapons - parts[0].weapons).abs() <= 1));
+        }
     }
     
     #[test]
-    fn rounds_weapons_refined_down_correctly_whole_value() {
+    fn splits_negative_currencies() {
         let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23),
+            keys: -2,
+            weapons: -5,
         };
         
-        assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(23));
+        assert_eq!(
+            currencies.split(3).collect::<Vec<_>>(),
+            vec![
+                Currencies {
+                    keys: -1,
+                    weapons: -2,
+                },
+                Currencies {
+                    keys: -1,
+                    weapons: -2,
+                },
+                Currencies {
+                    keys: 0,
+                    weapons: -1,
+                },
+            ],
+        );
     }
     
     #[test]
-    fn rounds_weapons_refined_up_correctly() {
+    fn splits_currencies_into_zero_parts() {
         let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23) + scrap!(5),
+            keys: 2,
+            weapons: 0,
         };
         
-        assert_eq!(currencies.round(&Rounding::Refined).weapons, refined!(24));
+        assert_eq!(currencies.split(0).count(), 0);
+        assert_eq!(currencies.split(-1).count(), 0);
     }
     
     #[test]
-    fn rounds_weapons_up() {
+    fn splits_currencies_into_max_parts_lazily() {
         let currencies = Currencies {
-            keys: 1,
-            weapons: refined!(23) + scrap!(4) + 1,
+            keys: 2,
+            weapons: 1,
         };
+        let mut parts = currencies.split(Currency::MAX);
         
-        assert_eq!(currencies.round(&Rounding::UpScrap).weapons, 424);
+        assert_eq!(parts.next(), Some(Currencies { keys: 1, weapons: 1 }));
+        assert_eq!(parts.next(), Some(Currencies { keys: 1, weapons: 0 }));
+        assert_eq!(parts.next(), Some(Currencies { keys: 0, weapons: 0 }));
     }
     
     #[test]
-    fn neatens() {
-        let currenices = Currencies {
+    fn to_weapons_clamped_price() {
+        let min_price = refined!(40);
+        let currencies = Currencies {
             keys: 1,
-            weapons: refined!(110),
+            weapons: scrap!(1),
         };
         
+        assert_eq!(currencies.to_weapons_clamped_price(0, min_price), refined!(40) + scrap!(1));
+        assert_eq!(currencies.to_weapons_clamped_price(-5, min_price), refined!(40) + scrap!(1));
         assert_eq!(
-            currenices.neaten(refined!(50)),
-            Currencies {
-                keys: 3,
-                weapons: refined!(10),
-            },
+            currencies.to_weapons_clamped_price(refined!(55), min_price),
+            refined!(55) + scrap!(1),
         );
     }
     
     #[test]
-    fn neatens_negative() {
+    fn to_weapons_nonneg() {
+        let key_price_weapons = refined!(50);
         let currencies = Currencies {
             keys: 1,
-            weapons: -refined!(110),
+            weapons: refined!(5),
         };
         
+        assert_eq!(currencies.to_weapons_nonneg(key_price_weapons), refined!(55));
+        assert_eq!((-currencies).to_weapons_nonneg(key_price_weapons), 0);
         assert_eq!(
-            currencies.neaten(refined!(50)),
             Currencies {
-                keys: -1,
-                weapons: -refined!(10),
-            },
+                keys: 1,
+                weapons: -refined!(60),
+            }.to_weapons_nonneg(key_price_weapons),
+            0,
         );
     }
     
     #[test]
-    fn neatens_negative_result_should_be_positive() {
+    fn apply_percent() {
         let currencies = Currencies {
-            keys: 2,
-            weapons: -refined!(60),
+            keys: 3,
+            weapons: refined!(10),
         };
         
         assert_eq!(
-            currencies.neaten(refined!(50)),
+            currencies.apply_percent(90.0),
             Currencies {
-                keys: 0,
-                weapons: refined!(40),
+                keys: 3,
+                weapons: refined!(9),
+            },
+        );
+        assert_eq!(
+            currencies.apply_percent(110.0),
+            Currencies {
+                keys: 3,
+                weapons: refined!(11),
             },
         );
+        assert_eq!(currencies.checked_apply_percent(110.0), Some(currencies.apply_percent(110.0)));
     }
     
     #[test]
-    fn to_weapons_with_negative_keys() {
-        let key_price_weapons = refined!(10);
+    fn apply_percent_value() {
+        let key_price_weapons = refined!(50);
         let currencies = Currencies {
-            keys: -10,
-            // 2 keys of metal, so the total should be -8 keys
-            weapons: key_price_weapons * 2,
+            keys: 3,
+            weapons: refined!(10),
         };
+        let weapons = currencies.to_weapons(key_price_weapons);
         
-        assert_eq!(currencies.to_weapons(key_price_weapons), -(key_price_weapons * 8));
-    }
-    
-    #[test]
-    fn greater_than() {
-        let a = Currencies { keys: 1, weapons: 5 };
-        let b = Currencies { keys: 0, weapons: 10 };
-        
-        assert!(a > b);
+        assert_eq!(
+            currencies.apply_percent_value(90.0, key_price_weapons),
+            Currencies::from_weapons(weapons * 9 / 10, key_price_weapons),
+        );
+        assert_eq!(
+            currencies.apply_percent_value(110.0, key_price_weapons),
+            Currencies::from_weapons(weapons * 11 / 10, key_price_weapons),
+        );
+        // Scaling fields independently loses the fractional key.
+        assert_ne!(
+            currencies.apply_percent(90.0).to_weapons(key_price_weapons),
+            weapons * 9 / 10,
+        );
     }
     
     #[test]
-    fn less_than() {
-        let a = Currencies { keys: 0, weapons: 1 };
-        let b = Currencies { keys: 0, weapons: 4 };
+    fn apply_percent_saturates() {
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: Currency::MIN,
+        };
         
-        assert!(a < b);
+        assert_eq!(currencies.apply_percent(200.0), currencies);
+        assert_eq!(currencies.checked_apply_percent(200.0), None);
+        assert_eq!(currencies.checked_apply_percent(f32::NAN), None);
     }
     
     #[test]
-    fn sorts() {
-        let mut currencies = vec![
-            Currencies { keys: 2, weapons: 4 },
-            Currencies { keys: 0, weapons: 2 },
-            Currencies { keys: 10, weapons: 4 },
-        ];
-        
-        // lowest to highest
-        currencies.sort();
+    fn apply_percent_is_exact_at_bounds() {
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: Currency::MIN,
+        };
+        let near_max = Currencies {
+            keys: Currency::MAX - 100,
+            weapons: Currency::MIN + 100,
+        };
         
+        assert_eq!(currencies.apply_percent(100.0), currencies);
+        assert_eq!(currencies.checked_apply_percent(100.0), Some(currencies));
+        assert_eq!(near_max.apply_percent(100.0), near_max);
+        assert_eq!(currencies.apply_percent(f32::INFINITY), currencies);
         assert_eq!(
-            *currencies.iter().rev().next().unwrap(),
-            Currencies { keys: 10, weapons: 4 },
+            currencies.apply_percent(f32::NEG_INFINITY),
+            Currencies {
+                keys: Currency::MIN,
+                weapons: Currency::MAX,
+            },
         );
     }
     
     #[test]
-    fn to_weapons_saturating_integer_bounds() {
-        let key_price_weapons = refined!(50);
+    fn scale_bps() {
+        let currencies = Currencies {
+            keys: 3,
+            weapons: refined!(10) + 1,
+        };
         
+        // 3 * 1.15 = 3.45 and 181 * 1.15 = 208.15, both truncated.
         assert_eq!(
+            currencies.scale_bps(11_500),
             Currencies {
-                keys: Currency::MAX - 100,
-                weapons: 0,
-            }.to_weapons(key_price_weapons),
-            Currency::MAX,
-        );
-        assert_eq!(
-            Currencies {
-                keys: Currency::MAX - 100,
-                weapons: 0,
-            }.to_weapons(-key_price_weapons),
-            Currency::MIN,
-        );
-        assert_eq!(
-            Currencies {
-                keys: 1,
-                weapons: Currency::MAX,
-            }.to_weapons(key_price_weapons),
-            Currency::MAX,
-        );
-        assert_eq!(
-            Currencies {
-                keys: -1,
-                weapons: Currency::MIN,
-            }.to_weapons(key_price_weapons),
-            Currency::MIN,
+                keys: 3,
+                weapons: 208,
+            },
         );
         assert_eq!(
+            (-currencies).scale_bps(11_500),
             Currencies {
-                keys: 1,
-                weapons: Currency::MIN,
-            }.to_weapons(key_price_weapons),
-            Currency::MIN + key_price_weapons,
+                keys: -3,
+                weapons: -208,
+            },
         );
+        assert_eq!(currencies.scale_bps(10_000), currencies);
+        assert_eq!(currencies.scale_bps(0), Currencies::default());
     }
     
     #[test]
-    fn checked_mul() {
-        assert_eq!(
-            Currencies {
-                keys: 2,
-                weapons: 0,
-            }.checked_mul(Currency::MAX),
-            None,
-        );
+    fn scale_bps_saturates() {
+        let currencies = Currencies {
+            keys: Currency::MAX,
+            weapons: Currency::MIN,
+        };
+        
+        assert_eq!(currencies.scale_bps(20_000), currencies);
+        assert_eq!(currencies.scale_bps(i64::MAX), currencies);
     }
     
     #[test]
-    fn checked_add() {
+    fn to_refined_string() {
+        let key_price_weapons = refined!(50);
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(36) + reclaimed!(1) + 3,
+        };
+        
+        assert_eq!(currencies.to_refined_string(key_price_weapons), "86.5 ref");
         assert_eq!(
             Currencies {
                 keys: 2,
                 weapons: 0,
-            }.checked_add(Currencies {
-                keys: Currency::MAX,
-                weapons: 0,
-            }),
-            None,
+            }.to_refined_string(key_price_weapons),
+            "100 ref",
         );
     }
     
     #[test]
-    fn checked_to_weapons() {
-        assert_eq!(
-            Currencies {
-                keys: Currency::MAX,
-                weapons: 0,
-            }.checked_to_weapons(Currency::MAX),
-            None,
-        );
+    fn display_keys() {
+        assert_eq!(Currencies { keys: 1, weapons: refined!(5) }.display_keys(), "1 key");
+        assert_eq!(Currencies { keys: 2, weapons: 0 }.display_keys(), "2 keys");
+        assert_eq!(Currencies { keys: 0, weapons: refined!(5) }.display_keys(), "0 keys");
     }
     
     #[test]
-    fn checked_to_weapons_correct_value() {
+    fn display_metal() {
         assert_eq!(
             Currencies {
-                keys: 10,
-                weapons: 5,
-            }.checked_to_weapons(10),
-            Some(105),
+                keys: 1,
+                weapons: refined!(23) + scrap!(4),
+            }.display_metal(),
+            "23.44 ref",
         );
+        assert_eq!(Currencies { keys: 0, weapons: refined!(5) }.display_metal(), "5 ref");
+        assert_eq!(Currencies { keys: 0, weapons: -scrap!(1) }.display_metal(), "-0.11 ref");
+        assert_eq!(Currencies { keys: 2, weapons: 0 }.display_metal(), "0 ref");
     }
     
     #[test]
-    fn from_float_currencies() {
-        let float_currencies = FloatCurrencies {
-            keys: 1.0,
-            metal: 1.33,
+    fn to_refined_string_zero_key_price() {
+        let currencies = Currencies {
+            keys: 2,
+            weapons: refined!(23) + scrap!(4),
         };
-        let currencies = Currencies::try_from(float_currencies).unwrap();
         
-        assert_eq!(currencies.weapons, refined!(1) + scrap!(3));
-    }
-    
-    #[test]
-    fn from_float_currencies_infinity() {
-        assert!(Currencies::try_from(FloatCurrencies {
-            keys: f32::INFINITY,
-            metal: 1.33,
-        }).is_err());
-        assert!(Currencies::try_from(FloatCurrencies {
-            keys: f32::NEG_INFINITY,
-            metal: 1.33,
-        }).is_err());
-    }
-    
-    #[test]
-    fn from_float_currencies_nan() {
-        assert!(Currencies::try_from(FloatCurrencies {
-            keys: f32::NAN,
-            metal: 1.33,
-        }).is_err());
-        assert!(Currencies::try_from(FloatCurrencies {
-            keys: f32::NAN,
-            metal: 1.33,
-        }).is_err());
+        assert_eq!(currencies.to_refined_string(0), "23.44 ref");
     }
     
     #[test]
-    fn from_float_currencies_does_not_overflow_bounds() {
-        assert!(Currencies::try_from(FloatCurrencies {
-            keys: Currency::MAX as f32 * 2.0,
-            metal: 1.33,
-        }).is_err());
+    fn parses_within_max_components() {
+        let options = ParseOptions::new().max_components(2);
+        
+        assert_eq!(
+            Currencies::parse_with("2 keys, 23.44 ref", &options).unwrap(),
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
     }
     
     #[test]
-    fn can_hash() {
-        let mut hash = std::collections::HashMap::<Currencies, i32>::new();
-        
-        hash.insert(Currencies {
-            keys: 1,
-            weapons: 1,
-        }, 1);
-        hash.insert(Currencies {
-            keys: 1,
-            weapons: 2,
-        }, 1);
+    fn parse_errors_with_too_many_components() {
+        let string = vec!["1 ref"; 1000].join(", ");
         
-        if let Some(value) = hash.get_mut(&Currencies {
-            keys: 1,
-            weapons: 1,
-        }) {
-            *value += 1;
-        }
-        
-        assert_eq!(
-            hash.get(&Currencies {
-                keys: 1,
-                weapons: 1,
-            }),
-            Some(&2),
-        );
-        assert_eq!(
-            hash.get(&Currencies {
-                keys: 1,
-                weapons: 2,
-            }),
-            Some(&1),
-        );
+        assert!(matches!(
+            string.parse::<Currencies>(),
+            Err(ParseError::TooManyComponents { max: ParseOptions::DEFAULT_MAX_COMPONENTS }),
+        ));
+        assert!(matches!(
+            Currencies::parse_with("2 keys, 5 ref, 1 ref", &ParseOptions::new().max_components(2)),
+            Err(ParseError::TooManyComponents { max: 2 }),
+        ));
     }
 }
 
@@ -1461,6 +6158,20 @@ mod tests_serde {
         );
     }
     
+    #[test]
+    fn deserializes_currencies_with_string_metal() {
+        let currencies: Currencies = serde_json::from_str(r#"{"keys":1,"metal":"23.44"}"#).unwrap();
+        
+        assert_eq!(
+            currencies,
+            Currencies {
+                keys: 1,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+        assert!(serde_json::from_str::<Currencies>(r#"{"keys":1,"metal":"23.44 ref"}"#).is_err());
+    }
+    
     #[test]
     fn deserializes_currencies_with_no_keys() {
         let currencies: Currencies = serde_json::from_str(r#"{"metal": 23.44}"#).unwrap();
@@ -0,0 +1,14 @@
+/// Denominations of TF2 currencies, from the most valuable to the least valuable.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Denomination {
+    /// Mann Co. Supply Crate Keys.
+    Keys,
+    /// Refined metal.
+    Refined,
+    /// Reclaimed metal.
+    Reclaimed,
+    /// Scrap metal.
+    Scrap,
+    /// Weapons, worth half a scrap.
+    Weapon,
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+/// Options for formatting currencies using
+/// [`Currencies::format_with`](crate::Currencies::format_with). The default options format
+/// currencies the same as their [`Display`](std::fmt::Display) implementation.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, DisplayOptions, refined};
+/// 
+/// let currencies = Currencies {
+///     keys: 2,
+///     weapons: refined!(5),
+/// };
+/// let options = DisplayOptions::new()
+///     .metal_decimals(2)
+///     .abbreviate_keys(true);
+/// 
+/// assert_eq!(currencies.format_with(&options), "2k, 5.00 ref");
+/// ```
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct DisplayOptions {
+    /// The number of decimal places to display metal with. If `None`, metal is displayed with up
+    /// to 2 decimal places.
+    pub metal_decimals: Option<usize>,
+    /// Whether to display metal when it is zero and keys are not.
+    pub always_show_metal: bool,
+    /// Whether to abbreviate keys as "k" e.g. `"2k"`.
+    pub abbreviate_keys: bool,
+}
+
+impl DisplayOptions {
+    /// Creates the default display options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Sets the number of decimal places to display metal with.
+    pub fn metal_decimals(mut self, metal_decimals: usize) -> Self {
+        self.metal_decimals = Some(metal_decimals);
+        self
+    }
+    
+    /// Sets whether to display metal when it is zero and keys are not.
+    pub fn always_show_metal(mut self, always_show_metal: bool) -> Self {
+        self.always_show_metal = always_show_metal;
+        self
+    }
+    
+    /// Sets whether to abbreviate keys as "k".
+    pub fn abbreviate_keys(mut self, abbreviate_keys: bool) -> Self {
+        self.abbreviate_keys = abbreviate_keys;
+        self
+    }
+}
\ No newline at end of file
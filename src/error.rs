@@ -1,5 +1,6 @@
 //! Error types.
 
+use crate::types::Currency;
 use std::num::{ParseFloatError, ParseIntError};
 use std::fmt;
 
@@ -43,22 +44,87 @@ pub enum ParseError {
     /// String was invalid.
     NoCurrenciesDetected,
     /// A number was expected, but none was found.
-    MissingCount,
+    MissingCount {
+        /// The byte range in the parsed string where the number was expected, if known.
+        span: Option<(usize, usize)>,
+    },
     /// A currency name was expected, but none was found.
     MissingCurrencyName,
     /// An unexpected element was found.
-    UnexpectedToken,
+    UnexpectedToken {
+        /// The unexpected element.
+        token: String,
+        /// The byte range of the unexpected element in the parsed string, if known.
+        span: Option<(usize, usize)>,
+    },
     /// An invalid currency name was found.
-    InvalidCurrencyName,
+    InvalidCurrencyName {
+        /// The invalid currency name.
+        token: String,
+        /// The byte range of the invalid currency name in the parsed string, if known.
+        span: Option<(usize, usize)>,
+    },
+    /// A number was written in scientific notation e.g. `"1e3"`, which is not supported.
+    ScientificNotation {
+        /// The byte range of the exponent in the parsed string, if known.
+        span: Option<(usize, usize)>,
+    },
+    /// The string contained more components e.g. `"2 keys"` than allowed.
+    TooManyComponents {
+        /// The maximum number of components allowed.
+        max: usize,
+    },
     /// A string failed to parse to an integer.
     ParseInt(ParseIntError),
     /// A string failed to parse to a float.
     ParseFloat(ParseFloatError),
 }
 
+impl ParseError {
+    /// Gets the byte range in the parsed string where the error occurred, if known.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let error = "2 keys, 3 what".parse::<Currencies>().unwrap_err();
+    /// 
+    /// assert_eq!(error.span(), Some((10, 14)));
+    /// ```
+    pub fn span(&self) -> Option<(usize, usize)> {
+        match self {
+            ParseError::MissingCount { span } |
+            ParseError::UnexpectedToken { span, .. } |
+            ParseError::InvalidCurrencyName { span, .. } |
+            ParseError::ScientificNotation { span } => *span,
+            _ => None,
+        }
+    }
+    
+    /// Shifts the span by `offset` bytes. Used when the parsed string is a slice of a larger
+    /// string so that the span points into the larger string.
+    pub(crate) fn offset_span(mut self, offset: usize) -> Self {
+        match &mut self {
+            ParseError::MissingCount { span } |
+            ParseError::UnexpectedToken { span, .. } |
+            ParseError::InvalidCurrencyName { span, .. } |
+            ParseError::ScientificNotation { span } => {
+                *span = span.map(|(start, end)| (start + offset, end + offset));
+            },
+            _ => {},
+        }
+        
+        self
+    }
+}
+
 impl std::error::Error for ParseError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match self {
+            ParseError::ParseInt(e) => Some(e),
+            ParseError::ParseFloat(e) => Some(e),
+            _ => None,
+        }
     }
 }
 
@@ -66,10 +132,12 @@ impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ParseError::NoCurrenciesDetected => write!(f, "No currencies could be parsed from string"),
-            ParseError::MissingCount => write!(f, "Expected a number, but none was found"),
+            ParseError::MissingCount { .. } => write!(f, "Expected a number, but none was found"),
             ParseError::MissingCurrencyName => write!(f, "Expected a currency name, but none was found"),
-            ParseError::UnexpectedToken => write!(f, "Unexpected token"),
-            ParseError::InvalidCurrencyName => write!(f, "Invalid currency name"),
+            ParseError::UnexpectedToken { token, .. } => write!(f, "Unexpected token: {:?}", token),
+            ParseError::InvalidCurrencyName { token, .. } => write!(f, "Invalid currency name: {:?}", token),
+            ParseError::ScientificNotation { .. } => write!(f, "Scientific notation is not supported"),
+            ParseError::TooManyComponents { max } => write!(f, "More than {} components were found", max),
             ParseError::ParseInt(e) => write!(f, "{}", e),
             ParseError::ParseFloat(e) => write!(f, "{}", e),
         }
@@ -86,4 +154,75 @@ impl From<ParseFloatError> for ParseError {
     fn from(e: ParseFloatError) -> Self {
         ParseError::ParseFloat(e)
     }
+}
+
+/// Error validating a price.
+#[derive(Debug)]
+pub enum PriceError {
+    /// The weapons value is not less than the key price, meaning the value was not neatened.
+    NotNeatened {
+        /// The weapons value.
+        weapons: Currency,
+        /// The key price (represented as weapons).
+        key_price_weapons: Currency,
+    },
+}
+
+impl std::error::Error for PriceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+impl fmt::Display for PriceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriceError::NotNeatened { weapons, key_price_weapons } => write!(
+                f,
+                "Weapons value of {} is not less than the key price of {}",
+                weapons,
+                key_price_weapons,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Currencies, FloatCurrencies};
+    use std::error::Error;
+    
+    #[test]
+    fn boxes_parse_error() {
+        let error: Box<dyn Error> = Box::new("2 what".parse::<Currencies>().unwrap_err());
+        
+        assert!(error.source().is_none());
+        assert_eq!(error.to_string(), r#"Invalid currency name: "what""#);
+    }
+    
+    #[test]
+    fn parse_error_source_is_inner_error() {
+        let error: Box<dyn Error> = Box::new("1.5 keys".parse::<Currencies>().unwrap_err());
+        let source = error.source().unwrap();
+        
+        assert!(source.is::<ParseIntError>());
+        
+        let error: Box<dyn Error> = Box::new("abc ref".parse::<Currencies>().unwrap_err());
+        let source = error.source().unwrap();
+        
+        assert!(source.is::<ParseFloatError>());
+    }
+    
+    #[test]
+    fn boxes_try_from_float_currencies_error() {
+        let error = Currencies::try_from(FloatCurrencies {
+            keys: 1.5,
+            metal: 0.0,
+        }).unwrap_err();
+        let error: Box<dyn Error> = Box::new(error);
+        
+        assert!(error.source().is_none());
+        assert_eq!(error.to_string(), "Currencies contains fractional value: 0.5");
+    }
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ use crate::constants::{KEYS_SYMBOL, KEY_SYMBOL, METAL_SYMBOL};
 use crate::Currencies;
 use std::fmt;
 use std::cmp::{Ord, Ordering};
+use std::hash::{Hash, Hasher};
 use auto_ops::impl_op_ex;
 
 /// For storing floating point values of currencies. This is useful for retaining the original 
@@ -66,6 +67,17 @@ impl Ord for FloatCurrencies {
 
 impl Eq for FloatCurrencies {}
 
+/// Hashes the bit representation of each field. `-0.0` is hashed the same as `0.0` to remain
+/// consistent with [`PartialEq`], which treats them as equal. NaN is never equal to itself, so
+/// currencies containing NaN cannot be looked up in a map.
+impl Hash for FloatCurrencies {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Adding 0.0 converts -0.0 into 0.0 and leaves all other values unchanged.
+        (self.keys + 0.0).to_bits().hash(state);
+        (self.metal + 0.0).to_bits().hash(state);
+    }
+}
+
 impl FloatCurrencies {
     /// Creates a new [`FloatCurrencies`] with `0` keys and `0` metal. Same as 
     /// `FloatCurrencies::default()`.
@@ -183,6 +195,50 @@ impl FloatCurrencies {
     pub fn can_afford(&self, other: &Self) -> bool {
         self.keys >= other.keys && self.metal >= other.metal
     }
+    
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if either field is not
+    /// finite afterwards.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::FloatCurrencies;
+    /// 
+    /// let currencies = FloatCurrencies { keys: f32::MAX, metal: 0.0 };
+    /// 
+    /// assert_eq!(currencies.checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(&self, rhs: Currency) -> Option<Self> {
+        (self * rhs).finite()
+    }
+    
+    /// Checked division. Computes `self / rhs`, returning `None` if `rhs == 0` or either field is
+    /// not finite afterwards.
+    pub fn checked_div(&self, rhs: Currency) -> Option<Self> {
+        if rhs == 0 {
+            return None;
+        }
+        
+        (self / rhs).finite()
+    }
+    
+    /// Adds currencies. `None` if either field is not finite afterwards.
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        (self + other).finite()
+    }
+    
+    /// Subtracts currencies. `None` if either field is not finite afterwards.
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        (self - other).finite()
+    }
+    
+    /// `None` if either field is NaN or infinite.
+    fn finite(self) -> Option<Self> {
+        if !self.keys.is_finite() || !self.metal.is_finite() {
+            return None;
+        }
+        
+        Some(self)
+    }
 }
 
 impl PartialEq<Currencies> for FloatCurrencies {
@@ -515,6 +571,7 @@ mod tests {
     }
     
     #[test]
+    #[allow(clippy::op_ref)]
     fn currencies_added_borrwed() {
         assert_eq!(
             FloatCurrencies {
@@ -549,6 +606,7 @@ mod tests {
     }
     
     #[test]
+    #[allow(clippy::op_ref)]
     fn currencies_subtracted_borrowed() {
         assert_eq!(
             FloatCurrencies {
@@ -767,6 +825,7 @@ mod tests {
     }
     
     #[test]
+    #[allow(clippy::useless_vec, clippy::manual_next_back)]
     fn sorts() {
         let mut currencies = vec![
             FloatCurrencies { keys: 2.0, metal: 4.0 },
@@ -807,6 +866,193 @@ mod tests {
             Some(100 + refined!(5)),
         );
     }
+    
+    #[test]
+    fn formats_currencies_singular_keys() {
+        assert_eq!(FloatCurrencies { keys: 1.0, metal: 0.0 }.to_string(), "1 key");
+        assert_eq!(FloatCurrencies { keys: -1.0, metal: 0.0 }.to_string(), "-1 key");
+    }
+    
+    #[test]
+    fn formats_currencies_plural_keys() {
+        assert_eq!(FloatCurrencies { keys: 0.0, metal: 2.0 }.to_string(), "2 ref");
+        assert_eq!(FloatCurrencies { keys: 0.0, metal: 0.0 }.to_string(), "0 keys, 0 ref");
+        assert_eq!(FloatCurrencies { keys: 2.0, metal: 0.0 }.to_string(), "2 keys");
+        assert_eq!(FloatCurrencies { keys: -2.0, metal: 0.0 }.to_string(), "-2 keys");
+    }
+    
+    #[test]
+    fn checked_mul() {
+        assert_eq!(
+            FloatCurrencies {
+                keys: 2.0,
+                metal: 1.5,
+            }.checked_mul(2),
+            Some(FloatCurrencies {
+                keys: 4.0,
+                metal: 3.0,
+            }),
+        );
+        assert_eq!(
+            FloatCurrencies {
+                keys: 2.0,
+                metal: f32::MAX,
+            }.checked_mul(Currency::MAX),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_div() {
+        assert_eq!(
+            FloatCurrencies {
+                keys: 2.0,
+                metal: 1.5,
+            }.checked_div(2),
+            Some(FloatCurrencies {
+                keys: 1.0,
+                metal: 0.75,
+            }),
+        );
+        assert_eq!(
+            FloatCurrencies {
+                keys: 2.0,
+                metal: 0.0,
+            }.checked_div(0),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_add() {
+        assert_eq!(
+            FloatCurrencies {
+                keys: 2.0,
+                metal: 0.0,
+            }.checked_add(FloatCurrencies {
+                keys: f32::MAX,
+                metal: 0.0,
+            }),
+            Some(FloatCurrencies {
+                keys: f32::MAX,
+                metal: 0.0,
+            }),
+        );
+        assert_eq!(
+            FloatCurrencies {
+                keys: f32::MAX,
+                metal: 0.0,
+            }.checked_add(FloatCurrencies {
+                keys: f32::MAX,
+                metal: 0.0,
+            }),
+            None,
+        );
+        assert_eq!(
+            FloatCurrencies {
+                keys: f32::NAN,
+                metal: 0.0,
+            }.checked_add(FloatCurrencies::default()),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_sub() {
+        assert_eq!(
+            FloatCurrencies {
+                keys: 2.0,
+                metal: 1.5,
+            }.checked_sub(FloatCurrencies {
+                keys: 1.0,
+                metal: 0.5,
+            }),
+            Some(FloatCurrencies {
+                keys: 1.0,
+                metal: 1.0,
+            }),
+        );
+        assert_eq!(
+            FloatCurrencies {
+                keys: 0.0,
+                metal: f32::MIN,
+            }.checked_sub(FloatCurrencies {
+                keys: 0.0,
+                metal: f32::MAX,
+            }),
+            None,
+        );
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_with_weapons() {
+        assert_eq!(
+            FloatCurrencies::try_from("1.5 keys, 1 ref, 9 weapons").unwrap(),
+            FloatCurrencies {
+                keys: 1.5,
+                metal: 1.5,
+            },
+        );
+    }
+    
+    #[test]
+    fn can_hash() {
+        let mut hash = std::collections::HashMap::<FloatCurrencies, i32>::new();
+        
+        hash.insert(FloatCurrencies {
+            keys: 1.0,
+            metal: 1.33,
+        }, 1);
+        hash.insert(FloatCurrencies {
+            keys: 1.0,
+            metal: 1.44,
+        }, 1);
+        
+        if let Some(value) = hash.get_mut(&FloatCurrencies {
+            keys: 1.0,
+            metal: 1.33,
+        }) {
+            *value += 1;
+        }
+        
+        assert_eq!(
+            hash.get(&FloatCurrencies {
+                keys: 1.0,
+                metal: 1.33,
+            }),
+            Some(&2),
+        );
+        assert_eq!(hash.len(), 2);
+    }
+    
+    #[test]
+    fn hashes_negative_zero_as_zero() {
+        use std::collections::hash_map::DefaultHasher;
+        
+        let hash = |currencies: &FloatCurrencies| {
+            let mut hasher = DefaultHasher::new();
+            
+            currencies.hash(&mut hasher);
+            hasher.finish()
+        };
+        let zero = FloatCurrencies {
+            keys: 0.0,
+            metal: 0.0,
+        };
+        let negative_zero = FloatCurrencies {
+            keys: -0.0,
+            metal: -0.0,
+        };
+        
+        assert_eq!(zero, negative_zero);
+        assert_eq!(hash(&zero), hash(&negative_zero));
+        
+        let mut map = std::collections::HashMap::new();
+        
+        map.insert(zero, 1);
+        
+        assert_eq!(map.get(&negative_zero), Some(&1));
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -1,7 +1,17 @@
 use crate::error::ParseError;
 use crate::types::Currency;
-use crate::constants::{KEYS_SYMBOL, KEY_SYMBOL, METAL_SYMBOL, ONE_REF, ONE_REF_FLOAT};
-use crate::Rounding;
+use crate::constants::{
+    KEYS_SYMBOL,
+    KEY_SYMBOL,
+    METAL_SYMBOL,
+    WEAPONS_SYMBOL,
+    WEAPON_SYMBOL,
+    ONE_REF,
+    ONE_REC,
+    ONE_SCRAP,
+    ONE_REF_FLOAT,
+};
+use crate::{ParseOptions, Rounding};
 
 /// Converts currencies to a metal value using the given key price (represented as weapons). This
 /// method is saturating.
@@ -37,13 +47,13 @@ pub fn pluralize<'a>(
     }
 }
 
-/// Pluralizes a value using a float as the test.
+/// Pluralizes a value using a float as the test. Both `1.0` and `-1.0` are singular.
 pub fn pluralize_float<'a>(
     amount: f32,
     singular: &'a str,
     plural: &'a str,
 ) -> &'a str {
-    if amount == 1.0 {
+    if amount.abs() == 1.0 {
         singular
     } else {
         plural
@@ -70,6 +80,15 @@ pub fn get_metal_float_from_weapons(value: Currency) -> f32 {
     f32::trunc((value as f32 / ONE_REF_FLOAT) * 100.0) / 100.0
 }
 
+/// Formats a value in weapons as refined with a fixed number of decimal places. Like
+/// [`get_metal_float_from_weapons`], the value is truncated rather than rounded.
+pub fn format_metal_fixed(value: Currency, decimals: usize) -> String {
+    let factor = 10_f64.powi(decimals as i32);
+    let metal = ((value as f64 / ONE_REF as f64) * factor).trunc() / factor;
+    
+    format!("{metal:.decimals$}")
+}
+
 /// Converts a float value into a metal value (represented as weapons).
 ///
 /// # Examples
@@ -94,6 +113,35 @@ pub fn checked_get_weapons_from_metal_float(value: f32) -> Option<Currency> {
     strict_f32_to_currency(metal)
 }
 
+/// Converts a float value into a metal value (represented as weapons). Same as
+/// [`get_weapons_from_metal_float`] but computed as an `f64`, which avoids losing precision for
+/// large values.
+///
+/// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+///
+/// # Examples
+/// ```
+/// assert_eq!(tf2_price::get_weapons_from_metal_float_f64(0.33), 6);
+/// ```
+pub fn get_weapons_from_metal_float_f64(value: f64) -> Currency {
+    // Casting a float to an integer saturates at the bounds of the integer.
+    (value * ONE_REF as f64).round() as Currency
+}
+
+/// Converts a float value into a metal value. Same as [`checked_get_weapons_from_metal_float`]
+/// but computed as an `f64`, which avoids losing precision for large values.
+/// 
+/// Checks for safe conversion.
+///
+/// # Examples
+/// ```
+/// assert_eq!(tf2_price::checked_get_weapons_from_metal_float_f64(0.33), Some(6));
+/// assert_eq!(tf2_price::checked_get_weapons_from_metal_float_f64(f64::NAN), None);
+/// ```
+pub fn checked_get_weapons_from_metal_float_f64(value: f64) -> Option<Currency> {
+    checked_f64_to_currency((value * ONE_REF as f64).round())
+}
+
 /// Converts an `f32` into a `Currency` safely.
 pub fn strict_f32_to_currency(value: f32) -> Option<Currency> {
     // We don't want to allow NaN or infinite values.
@@ -116,63 +164,349 @@ pub fn strict_f32_to_currency(value: f32) -> Option<Currency> {
     Some(value.trunc() as Currency)
 }
 
-/// Parses currencies from a string.
+/// Converts an `f64` into a `Currency` safely. The value is truncated towards zero. `None` if
+/// the value is NaN, infinite, or out of bounds.
+pub fn checked_f64_to_currency(value: f64) -> Option<Currency> {
+    if !value.is_finite() {
+        return None;
+    }
+    
+    // Casting to an i128 first avoids imprecision at the bounds of the f64 representation of
+    // Currency. Values beyond the bounds of an i128 saturate and fail the conversion below.
+    Currency::try_from(value as i128).ok()
+}
+
+/// Gets the number of whole refined in a value in weapons.
+/// 
+/// Negative values produce negative parts, so recombining the parts always gives back the
+/// original value.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currency, refined, scrap};
+/// 
+/// const REFINED: Currency = tf2_price::refined_part(refined!(23) + scrap!(4));
+/// 
+/// assert_eq!(REFINED, 23);
+/// ```
+pub const fn refined_part(weapons: Currency) -> Currency {
+    weapons / ONE_REF
+}
+
+/// Gets the number of whole reclaimed in a value in weapons after taking out refined.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{refined, scrap};
+/// 
+/// assert_eq!(tf2_price::reclaimed_part(refined!(23) + scrap!(4)), 1);
+/// ```
+pub const fn reclaimed_part(weapons: Currency) -> Currency {
+    (weapons % ONE_REF) / ONE_REC
+}
+
+/// Gets the number of whole scrap in a value in weapons after taking out refined and reclaimed.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{refined, scrap};
+/// 
+/// assert_eq!(tf2_price::scrap_part(refined!(23) + scrap!(4)), 1);
+/// ```
+pub const fn scrap_part(weapons: Currency) -> Currency {
+    (weapons % ONE_REC) / ONE_SCRAP
+}
+
+/// Gets the number of weapons left over in a value in weapons after taking out refined,
+/// reclaimed, and scrap.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{refined, scrap};
+/// 
+/// assert_eq!(tf2_price::weapon_part(refined!(23) + scrap!(4) + 1), 1);
+/// ```
+pub const fn weapon_part(weapons: Currency) -> Currency {
+    weapons % ONE_SCRAP
+}
+
+/// Converts keys and weapons into a total value in weapons using the given key price
+/// (represented as weapons). Computed as an `i128`, which cannot overflow.
+pub fn to_weapons_i128(keys: Currency, weapons: Currency, key_price_weapons: Currency) -> i128 {
+    keys as i128 * key_price_weapons as i128 + weapons as i128
+}
+
+/// Converts an `i128` into a [`Currency`], clamping the value to the bounds of [`Currency`].
+pub fn saturating_i128_to_currency(value: i128) -> Currency {
+    value.clamp(Currency::MIN as i128, Currency::MAX as i128) as Currency
+}
+
+/// Divides two integers, rounding to the nearest integer. Halves are rounded away from zero.
+pub fn round_div_i128(numerator: i128, denominator: i128) -> i128 {
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+    let half = denominator / 2;
+    
+    if numerator >= 0 {
+        (numerator + half) / denominator
+    } else {
+        (numerator - half) / denominator
+    }
+}
+
+/// Applies a percentage to a value e.g. `90.0` for 90%, rounding to the nearest integer. The
+/// percentage is taken to four decimal places so that the multiplication is exact. Results
+/// beyond the bounds of an `i128` saturate.
+pub fn percent_of_i128(value: Currency, percent: f32) -> i128 {
+    // 100% to four decimal places.
+    const ONE_HUNDRED_PERCENT: i128 = 100 * 10_000;
+    
+    // Casting a float to an integer saturates at the bounds of the integer.
+    let percent = (percent as f64 * 10_000.0).round() as i128;
+    let product = (value as i128)
+        .checked_mul(percent)
+        // Leave room for rounding. Anything this large is far beyond the bounds of Currency.
+        .filter(|product| product.unsigned_abs() <= (i128::MAX / 2) as u128);
+    
+    match product {
+        Some(product) => round_div_i128(product, ONE_HUNDRED_PERCENT),
+        None if (value < 0) == (percent < 0) => i128::MAX,
+        None => i128::MIN,
+    }
+}
+
+/// Finds the word "and" (case-insensitive) surrounded by whitespace, returning its byte range.
+fn find_and_separator(string: &str) -> Option<(usize, usize)> {
+    let bytes = string.as_bytes();
+    
+    (0..bytes.len().saturating_sub(2)).find(|&i| {
+        bytes[i..i + 3].eq_ignore_ascii_case(b"and") &&
+        (i == 0 || bytes[i - 1].is_ascii_whitespace()) &&
+        bytes.get(i + 3).is_some_and(u8::is_ascii_whitespace)
+    }).map(|i| (i, i + 3))
+}
+
+/// Splits an element on the word "and" e.g. "2 keys and 3 ref". If the element directly follows
+/// a comma, an empty piece before its first "and" is skipped so that "2 keys, and 3 ref" is also
+/// accepted. Any other empty piece is kept so that it fails to parse.
+fn split_on_and(element: &str, after_comma: bool) -> impl Iterator<Item = &str> {
+    let mut remaining = Some(element);
+    let mut is_first = true;
+    
+    std::iter::from_fn(move || {
+        loop {
+            let string = remaining?;
+            let skip_empty = after_comma && is_first;
+            
+            is_first = false;
+            
+            if let Some((start, end)) = find_and_separator(string) {
+                remaining = Some(&string[end..]);
+                
+                if skip_empty && string[..start].trim().is_empty() {
+                    continue;
+                }
+                
+                return Some(&string[..start]);
+            }
+            
+            remaining = None;
+            
+            return Some(string);
+        }
+    })
+}
+
+/// Gets the byte range of `token` within `string`. `token` must be a subslice of `string`.
+pub fn span_of(string: &str, token: &str) -> (usize, usize) {
+    let start = token.as_ptr() as usize - string.as_ptr() as usize;
+    
+    (start, start + token.len())
+}
+
+/// Gets the length of the exponent at the start of a string e.g. "e3" or "E-3", if any.
+fn exponent_len(string: &str) -> Option<usize> {
+    let bytes = string.as_bytes();
+    
+    if !matches!(bytes.first(), Some(b'e' | b'E')) {
+        return None;
+    }
+    
+    let sign_len = usize::from(matches!(bytes.get(1), Some(b'+' | b'-')));
+    let digits_len = bytes[1 + sign_len..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    
+    (digits_len > 0).then_some(1 + sign_len + digits_len)
+}
+
+/// The count strings for keys, metal, and weapons parsed from a string, if present.
+type CountStrings<'a> = (Option<&'a str>, Option<&'a str>, Option<&'a str>);
+
+/// Parses currencies from a string, erroring if the string contains more than
+/// `max_components` components.
 fn parse_currencies(
     string: &str,
-) -> Result<(Option<&str>, Option<&str>), ParseError> {
+    max_components: usize,
+) -> Result<CountStrings<'_>, ParseError> {
+    if string.trim().is_empty() {
+        return Err(ParseError::NoCurrenciesDetected);
+    }
+    
     let mut keys = None;
     let mut metal = None;
+    let mut weapons = None;
     
-    for element in string.split(',') {
-        let mut element_split = element.trim().split(' ');
-        let count_str = element_split.next().ok_or(ParseError::MissingCount)?;
-        let currency_name = element_split.next().ok_or(ParseError::MissingCurrencyName)?;
+    let elements = string
+        .split(',')
+        .enumerate()
+        .flat_map(|(index, element)| split_on_and(element, index > 0));
+    
+    for (index, element) in elements.enumerate() {
+        // Bail out early rather than walking the rest of a pathologically long string.
+        if index >= max_components {
+            return Err(ParseError::TooManyComponents {
+                max: max_components,
+            });
+        }
+        
+        let trimmed = element.trim();
+        
+        // An empty element e.g. after a trailing comma is missing its count.
+        if trimmed.is_empty() {
+            return Err(ParseError::MissingCount {
+                span: Some(span_of(string, element)),
+            });
+        }
+        
+        let mut element_split = trimmed.split(' ');
+        // Splitting a non-empty string always yields at least one element.
+        let first = element_split.next().unwrap_or_default();
+        // The count and currency name may be written without a space between them e.g. "2keys".
+        let (count_str, currency_name) = match first.find(|c: char| c.is_alphabetic()) {
+            Some(index) if index > 0 => first.split_at(index),
+            _ => (first, element_split.next().ok_or(ParseError::MissingCurrencyName)?),
+        };
+        
+        // Rust's float parser accepts scientific notation e.g. "1e3", which is almost certainly
+        // not what was meant.
+        if let Some(len) = exponent_len(currency_name) {
+            return Err(ParseError::ScientificNotation {
+                span: Some(span_of(string, &currency_name[..len])),
+            });
+        }
         
         // We don't expect another element after the currency name.
-        if element_split.next().is_some() {
-            return Err(ParseError::UnexpectedToken);
+        if let Some(token) = element_split.next() {
+            return Err(ParseError::UnexpectedToken {
+                token: token.to_string(),
+                span: Some(span_of(string, token)),
+            });
         }
         
         if currency_name.eq_ignore_ascii_case(METAL_SYMBOL) {
             metal = Some(count_str);
         } else if currency_name.eq_ignore_ascii_case(KEYS_SYMBOL) || currency_name.eq_ignore_ascii_case(KEY_SYMBOL) {
             keys = Some(count_str);
+        } else if currency_name.eq_ignore_ascii_case(WEAPONS_SYMBOL) || currency_name.eq_ignore_ascii_case(WEAPON_SYMBOL) {
+            weapons = Some(count_str);
         } else {
-            return Err(ParseError::InvalidCurrencyName);
+            return Err(ParseError::InvalidCurrencyName {
+                token: currency_name.to_string(),
+                span: Some(span_of(string, currency_name)),
+            });
         }
     }
     
-    if keys.is_none() && metal.is_none() {
+    if keys.is_none() && metal.is_none() && weapons.is_none() {
         return Err(ParseError::NoCurrenciesDetected);
     }
     
-    Ok((keys, metal))
+    Ok((keys, metal, weapons))
 }
 
-/// Parses currencies from a string.
+/// Parses the metal and weapon count strings into a single value in weapons. Metal and weapons
+/// are summed when both are present. This is saturating.
+fn parse_metal_and_weapons(
+    metal: Option<&str>,
+    weapons: Option<&str>,
+) -> Result<Currency, ParseError> {
+    let metal = metal
+        .map(|s| s.parse::<f32>())
+        .transpose()?
+        // Convert the metal value to a weapon value.
+        .map(get_weapons_from_metal_float)
+        .unwrap_or_default();
+    let weapons = weapons
+        .map(|s| s.parse::<Currency>())
+        .transpose()?
+        .unwrap_or_default();
+    
+    Ok(metal.saturating_add(weapons))
+}
+
+/// Parses currencies from a string, erroring if the string contains more than
+/// `max_components` components.
 pub fn parse_currency_from_string(
     string: &str,
+    max_components: usize,
 ) -> Result<(Currency, Currency), ParseError> {
-    let (keys, metal) = parse_currencies(string)?;
+    let (keys, metal, weapons) = parse_currencies(string, max_components)?;
     let keys = keys
         .map(|s| s.parse::<Currency>())
         .transpose()?
         .unwrap_or_default();
-    let metal = metal
-        .map(|s| s.parse::<f32>())
+    let metal = parse_metal_and_weapons(metal, weapons)?;
+    
+    Ok((keys, metal))
+}
+
+/// Parses a metal value from a string containing only metal, erroring if keys are present.
+pub fn parse_metal_only_from_string(
+    string: &str,
+) -> Result<Currency, ParseError> {
+    let (keys, metal, weapons) = parse_currencies(string, ParseOptions::DEFAULT_MAX_COMPONENTS)?;
+    
+    if let Some(keys) = keys {
+        return Err(ParseError::UnexpectedToken {
+            token: keys.to_string(),
+            span: Some(span_of(string, keys)),
+        });
+    }
+    
+    parse_metal_and_weapons(metal, weapons)
+}
+
+/// Parses a key value from a string containing only keys, erroring if metal is present.
+pub fn parse_keys_only_from_string(
+    string: &str,
+) -> Result<Currency, ParseError> {
+    let (keys, metal, weapons) = parse_currencies(string, ParseOptions::DEFAULT_MAX_COMPONENTS)?;
+    
+    if let Some(metal) = metal.or(weapons) {
+        return Err(ParseError::UnexpectedToken {
+            token: metal.to_string(),
+            span: Some(span_of(string, metal)),
+        });
+    }
+    
+    let keys = keys
+        .map(|s| s.parse::<Currency>())
         .transpose()?
-        // Convert the metal value to a weapon value.
-        .map(get_weapons_from_metal_float)
         .unwrap_or_default();
     
-    Ok((keys, metal))
+    Ok(keys)
 }
 
 /// Parses currencies from a string.
 pub fn parse_float_from_string(
     string: &str,
 ) -> Result<(f32, f32), ParseError> {
-    let (keys, metal) = parse_currencies(string)?;
+    let (keys, metal, weapons) = parse_currencies(string, ParseOptions::DEFAULT_MAX_COMPONENTS)?;
     let keys = keys
         .map(|s| s.parse::<f32>())
         .transpose()?
@@ -181,8 +515,30 @@ pub fn parse_float_from_string(
         .map(|s| s.parse::<f32>())
         .transpose()?
         .unwrap_or_default();
+    let weapons = weapons
+        .map(|s| s.parse::<Currency>())
+        .transpose()?
+        .unwrap_or_default();
     
-    Ok((keys, metal))
+    // Weapons are converted to refined so they can be added to the metal value.
+    Ok((keys, metal + weapons as f32 / ONE_REF_FLOAT))
+}
+
+/// Rounds a value to the nearest multiple of `unit`. Halves are rounded away from zero, so
+/// negative values round the same as positive values.
+fn round_to_nearest(value: Currency, unit: Currency) -> Currency {
+    let unit = unit as i128;
+    let rounded = round_div_i128(value as i128, unit) * unit;
+    
+    // The nearest multiple may be just beyond the bounds of Currency, in which case the next
+    // multiple towards zero is used.
+    if rounded > Currency::MAX as i128 {
+        (rounded - unit) as Currency
+    } else if rounded < Currency::MIN as i128 {
+        (rounded + unit) as Currency
+    } else {
+        rounded as Currency
+    }
 }
 
 /// Rounds a metal value.
@@ -204,11 +560,34 @@ pub fn round_metal(metal: Currency, rounding: &Rounding) -> Currency {
             // No rounding needed if the metal value is an even number.
             metal
         },
-        Rounding::Refined => {
-            let value = metal + ONE_REF / 2;
+        Rounding::Reclaimed => round_to_nearest(metal, ONE_REC),
+        Rounding::UpReclaimed => {
+            let remainder = metal % ONE_REC;
             
-            value - (value % ONE_REF)
+            if remainder != 0 {
+                if metal > 0 {
+                    metal - (remainder + -ONE_REC)
+                } else {
+                    metal - remainder
+                }
+            } else {
+                metal
+            }
         },
+        Rounding::DownReclaimed => {
+            let remainder = metal % ONE_REC;
+            
+            if remainder != 0 {
+                if metal > 0 {
+                    metal - remainder
+                } else {
+                    metal - (remainder + ONE_REC)
+                }
+            } else {
+                metal
+            }
+        },
+        Rounding::Refined => round_to_nearest(metal, ONE_REF),
         Rounding::UpRefined => {
             let remainder = metal % ONE_REF;
             
@@ -244,7 +623,7 @@ pub fn round_metal(metal: Currency, rounding: &Rounding) -> Currency {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::scrap;
+    use crate::{refined, reclaimed, scrap};
     
     #[test]
     fn converts_strict_f32_to_currency() {
@@ -270,4 +649,102 @@ mod tests {
     fn converts_to_metal_float() {
         assert_eq!(0.33, get_metal_float_from_weapons(6));
     }
+    
+    #[test]
+    fn gets_metal_parts_in_const_context() {
+        const WEAPONS: Currency = refined!(23) + reclaimed!(2) + scrap!(1) + 1;
+        const PARTS: [Currency; 4] = [
+            refined_part(WEAPONS),
+            reclaimed_part(WEAPONS),
+            scrap_part(WEAPONS),
+            weapon_part(WEAPONS),
+        ];
+        
+        assert_eq!(PARTS, [23, 2, 1, 1]);
+        assert_eq!(
+            refined!(PARTS[0]) + reclaimed!(PARTS[1]) + scrap!(PARTS[2]) + PARTS[3],
+            WEAPONS,
+        );
+    }
+    
+    #[test]
+    fn gets_metal_parts_negative() {
+        let weapons = -(refined!(2) + scrap!(2) + 1);
+        
+        assert_eq!(
+            refined!(refined_part(weapons)) +
+            reclaimed!(reclaimed_part(weapons)) +
+            scrap!(scrap_part(weapons)) +
+            weapon_part(weapons),
+            weapons,
+        );
+    }
+    
+    #[test]
+    fn rounds_negative_metal_to_nearest_reclaimed() {
+        assert_eq!(round_metal(-422, &Rounding::Reclaimed), -420);
+        assert_eq!(round_metal(-4, &Rounding::Reclaimed), -6);
+        assert_eq!(round_metal(-2, &Rounding::Reclaimed), 0);
+        assert_eq!(
+            round_metal(-(refined!(23) + reclaimed!(1) + 2), &Rounding::Reclaimed),
+            -(refined!(23) + reclaimed!(1)),
+        );
+        assert_eq!(round_metal(422, &Rounding::Reclaimed), 420);
+        assert_eq!(round_metal(4, &Rounding::Reclaimed), 6);
+    }
+    
+    #[test]
+    fn rounds_negative_metal_to_nearest_refined() {
+        assert_eq!(round_metal(-(refined!(2) + 8), &Rounding::Refined), -refined!(2));
+        assert_eq!(round_metal(-(refined!(2) + 9), &Rounding::Refined), -refined!(3));
+        assert_eq!(round_metal(refined!(2) + 9, &Rounding::Refined), refined!(3));
+    }
+    
+    #[test]
+    fn rounds_metal_to_nearest_at_bounds() {
+        for (rounding, unit) in [(Rounding::Reclaimed, ONE_REC), (Rounding::Refined, ONE_REF)] {
+            let max = round_metal(Currency::MAX, &rounding);
+            let min = round_metal(Currency::MIN, &rounding);
+            
+            assert_eq!(max % unit, 0);
+            assert!(Currency::MAX - max < unit);
+            assert_eq!(min % unit, 0);
+            assert!(min - Currency::MIN < unit);
+        }
+    }
+    
+    #[test]
+    fn round_div_i128_rounds_halves_away_from_zero() {
+        assert_eq!(round_div_i128(5, 2), 3);
+        assert_eq!(round_div_i128(-5, 2), -3);
+        assert_eq!(round_div_i128(4, 3), 1);
+        assert_eq!(round_div_i128(5, -2), -3);
+    }
+    
+    #[test]
+    fn pluralizes_float() {
+        assert_eq!(pluralize_float(1.0, "key", "keys"), "key");
+        assert_eq!(pluralize_float(-1.0, "key", "keys"), "key");
+        assert_eq!(pluralize_float(0.0, "key", "keys"), "keys");
+        assert_eq!(pluralize_float(2.0, "key", "keys"), "keys");
+        assert_eq!(pluralize_float(1.5, "key", "keys"), "keys");
+    }
+    
+    #[test]
+    fn converts_from_metal_float_f64_more_precisely_than_f32() {
+        // Beyond the precision of an f32 mantissa.
+        let refined = 53687091.33;
+        let expected = refined!(53687091) + 6;
+        
+        assert_eq!(get_weapons_from_metal_float_f64(refined), expected);
+        assert_eq!(checked_get_weapons_from_metal_float_f64(refined), Some(expected));
+        assert_ne!(get_weapons_from_metal_float(refined as f32), expected);
+    }
+    
+    #[test]
+    fn converts_from_metal_float_f64_out_of_bounds() {
+        assert_eq!(get_weapons_from_metal_float_f64(f64::MAX), Currency::MAX);
+        assert_eq!(checked_get_weapons_from_metal_float_f64(f64::MAX), None);
+        assert_eq!(checked_get_weapons_from_metal_float_f64(f64::INFINITY), None);
+    }
 }
\ No newline at end of file
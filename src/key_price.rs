@@ -0,0 +1,67 @@
+use crate::helpers;
+use crate::types::Currency;
+
+/// A key price represented as weapons. Using this type rather than a bare [`Currency`] makes the
+/// unit of the key price explicit at call sites.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, KeyPrice, refined};
+/// 
+/// let key_price = KeyPrice::from_refined(50.0);
+/// let currencies = Currencies {
+///     keys: 1,
+///     weapons: refined!(10),
+/// };
+/// 
+/// assert_eq!(key_price.weapons(), refined!(50));
+/// assert_eq!(currencies.to_weapons_key_price(key_price), refined!(60));
+/// ```
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct KeyPrice(Currency);
+
+impl KeyPrice {
+    /// Creates a key price from a value in weapons.
+    pub fn from_weapons(weapons: Currency) -> Self {
+        Self(weapons)
+    }
+    
+    /// Creates a key price from a float value in refined e.g. `50.11`. The value is rounded to
+    /// the nearest weapon.
+    /// 
+    /// This method is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+    pub fn from_refined(refined: f32) -> Self {
+        Self(helpers::get_weapons_from_metal_float(refined))
+    }
+    
+    /// Gets the key price in weapons.
+    pub fn weapons(&self) -> Currency {
+        self.0
+    }
+}
+
+impl From<KeyPrice> for Currency {
+    fn from(key_price: KeyPrice) -> Self {
+        key_price.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn creates_key_price_from_refined() {
+        assert_eq!(KeyPrice::from_refined(50.11).weapons(), refined!(50) + scrap!(1));
+        assert_eq!(
+            KeyPrice::from_refined(50.11),
+            KeyPrice::from_weapons(refined!(50) + scrap!(1)),
+        );
+    }
+    
+    #[test]
+    fn converts_key_price_into_currency() {
+        assert_eq!(Currency::from(KeyPrice::from_weapons(refined!(60))), refined!(60));
+    }
+}
\ No newline at end of file
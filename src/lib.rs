@@ -46,13 +46,29 @@
 #![warn(missing_docs)]
 
 pub mod error;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 
 mod types;
 mod helpers;
 mod currencies;
 mod float_currencies;
 mod rounding;
+mod overflow_policy;
+mod pricing_context;
+mod key_price;
+mod aligned_metal;
+mod parsed_price;
+mod denomination;
+mod components;
+mod metal_breakdown;
+mod display_options;
+mod parse_options;
 mod constants;
+mod aggregate;
+mod trade;
 #[cfg(feature = "serde")]
 mod serializers;
 
@@ -60,28 +76,42 @@ pub use currencies::Currencies;
 pub use float_currencies::FloatCurrencies;
 pub use types::Currency;
 pub use rounding::Rounding;
+pub use overflow_policy::OverflowPolicy;
+pub use pricing_context::PricingContext;
+pub use key_price::KeyPrice;
+pub use parsed_price::ParsedPrice;
+pub use denomination::Denomination;
+pub use components::Components;
+pub use metal_breakdown::{MetalBreakdown, metal_breakdown};
+pub use display_options::DisplayOptions;
+pub use parse_options::ParseOptions;
+pub use aligned_metal::{AlignedMetal, RefinedMetal, ReclaimedMetal, ScrapMetal};
 pub use helpers::{
     get_weapons_from_metal_float,
     checked_get_weapons_from_metal_float,
+    get_weapons_from_metal_float_f64,
+    checked_get_weapons_from_metal_float_f64,
     get_metal_float_from_weapons,
+    refined_part,
+    reclaimed_part,
+    scrap_part,
+    weapon_part,
 };
-pub use constants::{ONE_REF, ONE_REC, ONE_SCRAP, ONE_WEAPON};
-
-#[cfg(not(feature = "b32"))]
-/// Generates value for metal.
-#[macro_export]
-macro_rules! metal {
-    ( $a:expr ) => {
-        ($a as f32 * 18.0_f32).round() as i64
-    }
-}
+pub use aggregate::{
+    combine_listings,
+    min_by_value_iter,
+    max_by_value_iter,
+    mean,
+    dedup_by_value,
+};
+pub use trade::{parse_trade_summary, parse_trade_summary_with, profit};
+pub use constants::{ONE_REF, ONE_REC, ONE_SCRAP, ONE_WEAPON, ONE_REF_FLOAT};
 
-#[cfg(feature = "b32")]
 /// Generates value for metal.
 #[macro_export]
 macro_rules! metal {
     ( $a:expr ) => {
-        ($a as f32 * 18.0_f32).round() as i32
+        ($a as f32 * $crate::ONE_REF_FLOAT).round() as $crate::Currency
     }
 }
 
@@ -111,8 +141,6 @@ macro_rules! scrap {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-    
     #[test]
     fn metal_macro() {
         assert_eq!(metal!(1.0), 18);
@@ -123,4 +151,10 @@ mod tests {
         assert_eq!(metal!(1.99), 36);
         assert_eq!(metal!(50.66), 912);
     }
+    
+    #[test]
+    fn metal_macro_matches_one_ref() {
+        assert_eq!(metal!(1.0), crate::ONE_REF);
+        assert_eq!(metal!(23.0), refined!(23));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,92 @@
+use crate::helpers;
+use crate::types::Currency;
+
+/// The breakdown of a value in weapons into refined, reclaimed, scrap, and loose weapons. See
+/// [`metal_breakdown`] and [`Currencies::metal_breakdown`](crate::Currencies::metal_breakdown).
+/// 
+/// Negative values produce negative counts.
+#[derive(Debug, Default, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct MetalBreakdown {
+    /// Number of whole refined.
+    pub refined: Currency,
+    /// Number of whole reclaimed after taking out refined.
+    pub reclaimed: Currency,
+    /// Number of whole scrap after taking out refined and reclaimed.
+    pub scrap: Currency,
+    /// Number of weapons left over after taking out refined, reclaimed, and scrap.
+    pub weapon: Currency,
+}
+
+/// Breaks down a value in weapons into the fewest refined, reclaimed, scrap, and weapons needed
+/// to make it up.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{MetalBreakdown, metal_breakdown, refined, scrap};
+/// 
+/// assert_eq!(
+///     metal_breakdown(-(refined!(23) + scrap!(4) + 1)),
+///     MetalBreakdown {
+///         refined: -23,
+///         reclaimed: -1,
+///         scrap: -1,
+///         weapon: -1,
+///     },
+/// );
+/// ```
+pub const fn metal_breakdown(weapons: Currency) -> MetalBreakdown {
+    MetalBreakdown {
+        refined: helpers::refined_part(weapons),
+        reclaimed: helpers::reclaimed_part(weapons),
+        scrap: helpers::scrap_part(weapons),
+        weapon: helpers::weapon_part(weapons),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Currencies, refined, reclaimed, scrap};
+    
+    #[test]
+    fn breaks_down_metal() {
+        let currencies = Currencies {
+            keys: 3,
+            weapons: refined!(23) + scrap!(4) + 1,
+        };
+        
+        assert_eq!(
+            currencies.metal_breakdown(),
+            MetalBreakdown {
+                refined: 23,
+                reclaimed: 1,
+                scrap: 1,
+                weapon: 1,
+            },
+        );
+    }
+    
+    #[test]
+    fn breaks_down_negative_metal() {
+        assert_eq!(
+            metal_breakdown(-(refined!(23) + scrap!(4) + 1)),
+            MetalBreakdown {
+                refined: -23,
+                reclaimed: -1,
+                scrap: -1,
+                weapon: -1,
+            },
+        );
+    }
+    
+    #[test]
+    fn breaks_down_min_value() {
+        let MetalBreakdown { refined, reclaimed, scrap, weapon } = metal_breakdown(Currency::MIN);
+        
+        assert!(refined < 0);
+        assert_eq!(
+            refined!(refined) + reclaimed!(reclaimed) + scrap!(scrap) + weapon,
+            Currency::MIN,
+        );
+    }
+}
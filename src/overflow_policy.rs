@@ -0,0 +1,10 @@
+/// Overflow behaviors for conversions which may exceed the bounds of [`Currency`](crate::Currency).
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum OverflowPolicy {
+    /// Clamps the result to the bounds of the integer type.
+    Saturate,
+    /// Wraps around at the bounds of the integer type.
+    Wrap,
+    /// Fails on overflow.
+    Checked,
+}
\ No newline at end of file
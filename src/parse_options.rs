@@ -0,0 +1,44 @@
+/// Options for parsing currencies using
+/// [`Currencies::parse_with`](crate::Currencies::parse_with). The default options parse
+/// currencies the same as their [`FromStr`](std::str::FromStr) implementation.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, ParseOptions};
+/// 
+/// let options = ParseOptions::new().max_components(2);
+/// 
+/// assert!(Currencies::parse_with("2 keys, 5 ref", &options).is_ok());
+/// assert!(Currencies::parse_with("2 keys, 5 ref, 1 ref", &options).is_err());
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct ParseOptions {
+    /// The maximum number of components e.g. `"2 keys"` allowed in a string. Parsing stops
+    /// early with [`ParseError::TooManyComponents`](crate::error::ParseError::TooManyComponents)
+    /// once this is exceeded.
+    pub max_components: usize,
+}
+
+impl ParseOptions {
+    /// The default maximum number of components.
+    pub const DEFAULT_MAX_COMPONENTS: usize = 4;
+    
+    /// Creates the default parse options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Sets the maximum number of components allowed in a string.
+    pub fn max_components(mut self, max_components: usize) -> Self {
+        self.max_components = max_components;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_components: Self::DEFAULT_MAX_COMPONENTS,
+        }
+    }
+}
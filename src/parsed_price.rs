@@ -0,0 +1,13 @@
+use crate::Currencies;
+
+/// A price parsed from a listing, which may be a keyword rather than a numeric value. See
+/// [`Currencies::parse_special`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ParsedPrice {
+    /// A numeric price.
+    Value(Currencies),
+    /// The item is listed for free e.g. `"free"`.
+    Free,
+    /// The seller is accepting offers e.g. `"offer"`.
+    Offer,
+}
\ No newline at end of file
@@ -0,0 +1,119 @@
+use crate::types::Currency;
+use crate::{Currencies, FloatCurrencies};
+
+/// Holds a key price (represented as weapons) for performing conversions without passing the key
+/// price to each method. This is useful for applications which only use a single key price.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, PricingContext, refined};
+/// 
+/// let context = PricingContext::new(refined!(50));
+/// let currencies = Currencies {
+///     keys: 1,
+///     weapons: refined!(60),
+/// };
+/// 
+/// assert_eq!(context.to_weapons(&currencies), refined!(110));
+/// assert_eq!(context.neaten(&currencies), Currencies { keys: 2, weapons: refined!(10) });
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct PricingContext {
+    /// The price of a key represented as weapons.
+    pub key_price_weapons: Currency,
+}
+
+impl PricingContext {
+    /// Creates a new [`PricingContext`] using the given key price (represented as weapons).
+    pub fn new(key_price_weapons: Currency) -> Self {
+        Self {
+            key_price_weapons,
+        }
+    }
+    
+    /// Converts currencies to a weapon value. See [`Currencies::to_weapons`].
+    pub fn to_weapons(&self, currencies: &Currencies) -> Currency {
+        currencies.to_weapons(self.key_price_weapons)
+    }
+    
+    /// Converts currencies to a weapon value. See [`Currencies::checked_to_weapons`].
+    pub fn checked_to_weapons(&self, currencies: &Currencies) -> Option<Currency> {
+        currencies.checked_to_weapons(self.key_price_weapons)
+    }
+    
+    /// Converts a weapon value into currencies. See [`Currencies::from_weapons`].
+    pub fn from_weapons(&self, weapons: Currency) -> Currencies {
+        Currencies::from_weapons(weapons, self.key_price_weapons)
+    }
+    
+    /// Converts a weapon value into currencies. See [`Currencies::checked_from_weapons`].
+    pub fn checked_from_weapons(&self, weapons: Currency) -> Option<Currencies> {
+        Currencies::checked_from_weapons(weapons, self.key_price_weapons)
+    }
+    
+    /// Converts an f32 key value into currencies. See [`Currencies::from_keys_f32`].
+    pub fn from_keys_f32(&self, keys: f32) -> Currencies {
+        Currencies::from_keys_f32(keys, self.key_price_weapons)
+    }
+    
+    /// Converts from [`FloatCurrencies`]. See [`Currencies::from_float_currencies_with`].
+    pub fn from_float_currencies(&self, currencies: FloatCurrencies) -> Currencies {
+        Currencies::from_float_currencies_with(currencies, self.key_price_weapons)
+    }
+    
+    /// Neatens currencies. See [`Currencies::neaten`].
+    pub fn neaten(&self, currencies: &Currencies) -> Currencies {
+        currencies.neaten(self.key_price_weapons)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn converts_with_context() {
+        let context = PricingContext::new(refined!(50));
+        let currencies = Currencies {
+            keys: 1,
+            weapons: refined!(60) + scrap!(4),
+        };
+        let weapons = context.to_weapons(&currencies);
+        
+        assert_eq!(weapons, refined!(110) + scrap!(4));
+        assert_eq!(context.checked_to_weapons(&currencies), Some(weapons));
+        assert_eq!(
+            context.from_weapons(weapons),
+            Currencies {
+                keys: 2,
+                weapons: refined!(10) + scrap!(4),
+            },
+        );
+        assert_eq!(context.checked_from_weapons(weapons), Some(context.from_weapons(weapons)));
+        assert_eq!(context.neaten(&currencies), context.from_weapons(weapons));
+    }
+    
+    #[test]
+    fn converts_floats_with_context() {
+        let context = PricingContext::new(refined!(50));
+        
+        assert_eq!(
+            context.from_keys_f32(1.5),
+            Currencies {
+                keys: 1,
+                weapons: refined!(25),
+            },
+        );
+        assert_eq!(
+            context.from_float_currencies(FloatCurrencies {
+                keys: 1.5,
+                metal: 2.0,
+            }),
+            Currencies {
+                keys: 1,
+                weapons: refined!(27),
+            },
+        );
+    }
+}
\ No newline at end of file
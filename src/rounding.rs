@@ -5,6 +5,12 @@ pub enum Rounding {
     UpScrap,
     /// Rounds down to the nearest scrap.
     DownScrap,
+    /// Rounds to the nearest reclaimed.
+    Reclaimed,
+    /// Rounds up to the nearest reclaimed.
+    UpReclaimed,
+    /// Rounds down to the nearest reclaimed.
+    DownReclaimed,
     /// Rounds to the nearest refined.
     Refined,
     /// Rounds up to the nearest refined.
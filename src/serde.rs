@@ -0,0 +1,342 @@
+//! Helper modules for (de)serializing currencies in alternate formats. These are meant to be used
+//! with serde's `#[serde(with = "...")]` attribute.
+
+/// (De)serializes [`Currencies`](crate::Currencies) where metal is nested in an object e.g.
+/// `{"keys": 1, "metal": {"value": 23.44}}`. This format is used by some older data sources.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined, scrap};
+/// use serde::{Serialize, Deserialize};
+/// 
+/// #[derive(Serialize, Deserialize)]
+/// struct Listing {
+///     #[serde(with = "tf2_price::serde::nested_metal")]
+///     price: Currencies,
+/// }
+/// 
+/// let listing: Listing = serde_json::from_str(
+///     r#"{"price":{"keys":1,"metal":{"value":23.44}}}"#
+/// ).unwrap();
+/// 
+/// assert_eq!(listing.price, Currencies { keys: 1, weapons: refined!(23) + scrap!(4) });
+/// ```
+pub mod nested_metal {
+    use crate::helpers;
+    use crate::types::Currency;
+    use crate::Currencies;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::ser::SerializeStruct;
+    
+    #[derive(Deserialize)]
+    struct NestedMetal {
+        value: f32,
+    }
+    
+    #[derive(Deserialize)]
+    struct NestedCurrencies {
+        #[serde(default)]
+        keys: Currency,
+        #[serde(default)]
+        metal: Option<NestedMetal>,
+    }
+    
+    struct NestedMetalRef(Currency);
+    
+    impl serde::Serialize for NestedMetalRef {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut metal = serializer.serialize_struct("Metal", 1)?;
+            let float = helpers::get_metal_float_from_weapons(self.0);
+            
+            if float.fract() == 0.0 {
+                metal.serialize_field("value", &(float as Currency))?;
+            } else {
+                metal.serialize_field("value", &float)?;
+            }
+            
+            metal.end()
+        }
+    }
+    
+    /// Serializes [`Currencies`] with metal nested in an object.
+    pub fn serialize<S>(currencies: &Currencies, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut nested = serializer.serialize_struct("Currencies", 2)?;
+        
+        if currencies.keys == 0 {
+            nested.skip_field("keys")?;
+        } else {
+            nested.serialize_field("keys", &currencies.keys)?;
+        }
+        
+        if currencies.weapons == 0 {
+            nested.skip_field("metal")?;
+        } else {
+            nested.serialize_field("metal", &NestedMetalRef(currencies.weapons))?;
+        }
+        
+        nested.end()
+    }
+    
+    /// Deserializes [`Currencies`] with metal nested in an object.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currencies, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        
+        let nested = NestedCurrencies::deserialize(deserializer)?;
+        let weapons = nested.metal
+            .map(|metal| helpers::get_weapons_from_metal_float(metal.value))
+            .unwrap_or_default();
+        
+        if nested.keys == 0 && weapons == 0 {
+            return Err(D::Error::custom("Does not contain values for keys or metal"));
+        }
+        
+        Ok(Currencies {
+            keys: nested.keys,
+            weapons,
+        })
+    }
+}
+
+/// (De)serializes [`Currencies`](crate::Currencies) as a string e.g. `"2 keys, 23.44 ref"`.
+/// Serialization uses the [`Display`](std::fmt::Display) implementation and deserialization uses
+/// the [`FromStr`](std::str::FromStr) implementation.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined, scrap};
+/// use serde::{Serialize, Deserialize};
+/// 
+/// #[derive(Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "tf2_price::serde::as_string")]
+///     price: Currencies,
+/// }
+/// 
+/// let row = Row {
+///     price: Currencies { keys: 2, weapons: refined!(23) + scrap!(4) },
+/// };
+/// 
+/// assert_eq!(serde_json::to_string(&row).unwrap(), r#"{"price":"2 keys, 23.44 ref"}"#);
+/// ```
+pub mod as_string {
+    use crate::Currencies;
+    use serde::{Deserialize, Deserializer, Serializer};
+    
+    /// Serializes [`Currencies`] as a string.
+    pub fn serialize<S>(currencies: &Currencies, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(currencies)
+    }
+    
+    /// Deserializes [`Currencies`] from a string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currencies, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        
+        let string = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        
+        string.parse::<Currencies>().map_err(D::Error::custom)
+    }
+}
+
+/// (De)serializes [`Currencies`](crate::Currencies) with metal always as a float e.g.
+/// `{"keys": 1, "metal": 23.0}`. By default, metal without a fractional part is serialized as an
+/// integer, which some APIs reject. Deserialization is the same as the default.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// use serde::{Serialize, Deserialize};
+/// 
+/// #[derive(Serialize, Deserialize)]
+/// struct Listing {
+///     #[serde(with = "tf2_price::serde::metal_always_float")]
+///     price: Currencies,
+/// }
+/// 
+/// let listing = Listing {
+///     price: Currencies { keys: 1, weapons: refined!(23) },
+/// };
+/// 
+/// assert_eq!(serde_json::to_string(&listing).unwrap(), r#"{"price":{"keys":1,"metal":23.0}}"#);
+/// ```
+pub mod metal_always_float {
+    use crate::helpers;
+    use crate::Currencies;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::ser::SerializeStruct;
+    
+    /// Serializes [`Currencies`] with metal always as a float.
+    pub fn serialize<S>(currencies: &Currencies, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut float_currencies = serializer.serialize_struct("Currencies", 2)?;
+        
+        if currencies.keys == 0 {
+            float_currencies.skip_field("keys")?;
+        } else {
+            float_currencies.serialize_field("keys", &currencies.keys)?;
+        }
+        
+        if currencies.weapons == 0 {
+            float_currencies.skip_field("metal")?;
+        } else {
+            let float = helpers::get_metal_float_from_weapons(currencies.weapons);
+            
+            float_currencies.serialize_field("metal", &float)?;
+        }
+        
+        float_currencies.end()
+    }
+    
+    /// Deserializes [`Currencies`] the same as the default.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currencies, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Currencies as Deserialize>::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currencies, refined, scrap};
+    use serde::{Serialize, Deserialize};
+    use serde_json::{self, json, Value};
+    use assert_json_diff::assert_json_eq;
+    
+    #[derive(Debug, Serialize, Deserialize)]
+    struct NestedListing {
+        #[serde(with = "super::nested_metal")]
+        price: Currencies,
+    }
+    
+    #[test]
+    fn deserializes_nested_metal() {
+        let listing: NestedListing = serde_json::from_str(
+            r#"{"price":{"keys":2,"metal":{"value":23.44}}}"#
+        ).unwrap();
+        
+        assert_eq!(
+            listing.price,
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+    }
+    
+    #[test]
+    fn deserializes_nested_metal_with_no_keys() {
+        let listing: NestedListing = serde_json::from_str(
+            r#"{"price":{"metal":{"value":23.16}}}"#
+        ).unwrap();
+        
+        assert_eq!(
+            listing.price,
+            Currencies {
+                keys: 0,
+                weapons: refined!(23) + 3,
+            },
+        );
+    }
+    
+    #[test]
+    fn serializes_nested_metal() {
+        let listing = NestedListing {
+            price: Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        };
+        let listing_json = serde_json::to_string(&listing).unwrap();
+        let actual: Value = serde_json::from_str(&listing_json).unwrap();
+        let expected: Value = json!({
+            "price": {
+                "keys": 2,
+                "metal": {
+                    "value": 23.44
+                }
+            }
+        });
+        
+        assert_json_eq!(actual, expected);
+    }
+    
+    #[derive(Debug, Serialize, Deserialize)]
+    struct StringListing {
+        #[serde(with = "super::as_string")]
+        price: Currencies,
+    }
+    
+    #[test]
+    fn serializes_as_string() {
+        let listing = StringListing {
+            price: Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        };
+        let listing_json = serde_json::to_string(&listing).unwrap();
+        
+        assert_eq!(listing_json, r#"{"price":"2 keys, 23.44 ref"}"#);
+        
+        let listing: StringListing = serde_json::from_str(&listing_json).unwrap();
+        
+        assert_eq!(
+            listing.price,
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+    }
+    
+    #[test]
+    fn deserializes_as_string_invalid() {
+        assert!(serde_json::from_str::<StringListing>(r#"{"price":"2 what"}"#).is_err());
+    }
+    
+    #[derive(Debug, Serialize, Deserialize)]
+    struct FloatListing {
+        #[serde(with = "super::metal_always_float")]
+        price: Currencies,
+    }
+    
+    #[test]
+    fn serializes_metal_always_float() {
+        let listing = FloatListing {
+            price: Currencies {
+                keys: 1,
+                weapons: refined!(23),
+            },
+        };
+        let listing_json = serde_json::to_string(&listing).unwrap();
+        
+        assert_eq!(listing_json, r#"{"price":{"keys":1,"metal":23.0}}"#);
+        
+        let listing: FloatListing = serde_json::from_str(&listing_json).unwrap();
+        
+        assert_eq!(
+            listing.price,
+            Currencies {
+                keys: 1,
+                weapons: refined!(23),
+            },
+        );
+    }
+}
\ No newline at end of file
@@ -1,15 +1,62 @@
 use crate::types::Currency;
 use crate::constants::ONE_REF_FLOAT;
 use serde::Deserialize;
+use serde::de::{self, Visitor};
+use std::fmt;
 
-/// Deserializes float weapon values as weapons.
+/// Visits a float metal value given either as a number or as a string e.g. `"23.44"`.
+struct MetalVisitor;
+
+impl<'de> Visitor<'de> for MetalVisitor {
+    type Value = f32;
+    
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a float or a string containing a float")
+    }
+    
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value as f32)
+    }
+    
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value as f32)
+    }
+    
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value as f32)
+    }
+    
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value.trim().parse::<f32>().map_err(E::custom)
+    }
+}
+
+/// Deserializes float weapon values as weapons. The value may be given as a number or as a
+/// string e.g. `"23.44"`.
 pub fn metal_deserializer<'de, D>(deserializer: D) -> Result<Currency, D::Error>
 where
     D: serde::Deserializer<'de>
 {
     
     // get the metal value as a float e.g. 2.55 ref
-    let metal_refined_float = f32::deserialize(deserializer)?;
+    // strings are only possible in self-describing formats, which are human readable
+    let metal_refined_float = if deserializer.is_human_readable() {
+        deserializer.deserialize_any(MetalVisitor)?
+    } else {
+        f32::deserialize(deserializer)?
+    };
     // will fit it into the nearest weapon value
     let metal = (metal_refined_float * ONE_REF_FLOAT).round() as Currency;
     
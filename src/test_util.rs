@@ -0,0 +1,59 @@
+//! Assertions for testing conversions against this crate. Enabled with the `test-util`
+//! feature.
+
+use crate::Currencies;
+use crate::types::Currency;
+
+/// Asserts that converting currencies to weapons and back using the given key price (represented
+/// as weapons) produces the same value as neatening them.
+/// 
+/// # Panics
+/// Panics with the intermediate values if the round trip does not match.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// use tf2_price::test_util::assert_roundtrip;
+/// 
+/// assert_roundtrip(&Currencies { keys: 1, weapons: refined!(60) }, refined!(50));
+/// ```
+pub fn assert_roundtrip(currencies: &Currencies, key_price_weapons: Currency) {
+    let weapons = currencies.to_weapons(key_price_weapons);
+    let roundtrip = Currencies::from_weapons(weapons, key_price_weapons);
+    let neatened = currencies.neaten(key_price_weapons);
+    
+    assert!(
+        roundtrip == neatened,
+        "round trip of {currencies:?} at a key price of {key_price_weapons} weapons produced \
+        {roundtrip:?} (via {weapons} weapons), but neatening produced {neatened:?}",
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn roundtrips_currencies() {
+        for key_price_weapons in [1, refined!(50), refined!(60) + scrap!(1)] {
+            for currencies in [
+                Currencies::default(),
+                Currencies {
+                    keys: 2,
+                    weapons: refined!(23) + scrap!(4),
+                },
+                Currencies {
+                    keys: -1,
+                    weapons: refined!(80),
+                },
+                Currencies {
+                    keys: 0,
+                    weapons: -refined!(120) - 1,
+                },
+            ] {
+                assert_roundtrip(&currencies, key_price_weapons);
+            }
+        }
+    }
+}
\ No newline at end of file
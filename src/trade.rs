@@ -0,0 +1,244 @@
+use crate::helpers;
+use crate::Currencies;
+use crate::types::Currency;
+use crate::error::ParseError;
+
+/// Label for the offering side of a trade summary.
+const OFFERING_LABEL: &str = "offering:";
+/// Label for the asking side of a trade summary.
+const ASKING_LABEL: &str = "asking:";
+
+/// Strips a label from the start of a string, ignoring case.
+fn strip_label<'a>(string: &'a str, label: &str) -> &'a str {
+    match string.get(..label.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(label) => string[label.len()..].trim(),
+        _ => string,
+    }
+}
+
+/// Parses a trade summary e.g. `"Offering: 2 keys, 23.44 ref | Asking: 1 key"` into the offered
+/// and asked currencies. The `Offering:` and `Asking:` labels are optional. Sides are split on
+/// `|`; use [`parse_trade_summary_with`] to split on a different delimiter.
+/// 
+/// # Errors
+/// - The summary does not contain exactly two sides.
+/// - Either side fails to parse into [`Currencies`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, parse_trade_summary, refined, scrap};
+/// 
+/// let (offering, asking) = parse_trade_summary(
+///     "Offering: 2 keys, 23.44 ref | Asking: 1 key",
+/// ).unwrap();
+/// 
+/// assert_eq!(offering, Currencies { keys: 2, weapons: refined!(23) + scrap!(4) });
+/// assert_eq!(asking, Currencies { keys: 1, weapons: 0 });
+/// ```
+pub fn parse_trade_summary(string: &str) -> Result<(Currencies, Currencies), ParseError> {
+    parse_trade_summary_with(string, '|')
+}
+
+/// Parses a trade summary into the offered and asked currencies, splitting sides on the given
+/// delimiter. See [`parse_trade_summary`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, parse_trade_summary_with};
+/// 
+/// let (offering, asking) = parse_trade_summary_with("2 keys / 1 key", '/').unwrap();
+/// 
+/// assert_eq!(offering, Currencies { keys: 2, weapons: 0 });
+/// assert_eq!(asking, Currencies { keys: 1, weapons: 0 });
+/// ```
+pub fn parse_trade_summary_with(
+    string: &str,
+    delimiter: char,
+) -> Result<(Currencies, Currencies), ParseError> {
+    let mut sides = string.split(delimiter);
+    let offering = sides.next().ok_or(ParseError::NoCurrenciesDetected)?;
+    let asking = sides.next().ok_or(ParseError::NoCurrenciesDetected)?;
+    
+    // We don't expect another side after the asking side.
+    if let Some(side) = sides.next() {
+        return Err(ParseError::UnexpectedToken {
+            token: side.to_string(),
+            span: Some(helpers::span_of(string, side)),
+        });
+    }
+    
+    let offering = parse_side(string, strip_label(offering.trim(), OFFERING_LABEL))?;
+    let asking = parse_side(string, strip_label(asking.trim(), ASKING_LABEL))?;
+    
+    Ok((offering, asking))
+}
+
+/// Parses a side of a trade summary. Error spans point into the full summary rather than the
+/// side.
+fn parse_side(string: &str, side: &str) -> Result<Currencies, ParseError> {
+    let (offset, _) = helpers::span_of(string, side);
+    
+    side.parse::<Currencies>().map_err(|error| error.offset_span(offset))
+}
+
+/// Calculates the profit from buying at `buy` and selling at `sell` after a fee of
+/// `fee_percent` percent is taken from the sale, using the given key price (represented as
+/// weapons). The fee is rounded to the nearest weapon. The result is negative if the trade is a
+/// loss.
+/// 
+/// This function is [saturating](https://en.wikipedia.org/wiki/Saturation_arithmetic).
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, profit, refined};
+/// 
+/// let key_price_weapons = refined!(50);
+/// let buy = Currencies { keys: 1, weapons: 0 };
+/// let sell = Currencies { keys: 1, weapons: refined!(10) };
+/// 
+/// assert_eq!(
+///     profit(&buy, &sell, 10.0, key_price_weapons),
+///     Currencies { keys: 0, weapons: refined!(4) },
+/// );
+/// ```
+pub fn profit(
+    buy: &Currencies,
+    sell: &Currencies,
+    fee_percent: f32,
+    key_price_weapons: Currency,
+) -> Currencies {
+    let sell_weapons = sell.to_weapons_f64(key_price_weapons);
+    let fee = (sell_weapons * fee_percent as f64 / 100.0).round();
+    let profit = sell_weapons - fee - buy.to_weapons_f64(key_price_weapons);
+    
+    // Casting a float to an integer saturates at the bounds of the integer.
+    Currencies::from_weapons(profit as Currency, key_price_weapons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn parses_trade_summary() {
+        let (offering, asking) = parse_trade_summary(
+            "Offering: 2 keys, 23.44 ref | Asking: 1 key"
+        ).unwrap();
+        
+        assert_eq!(
+            offering,
+            Currencies {
+                keys: 2,
+                weapons: refined!(23) + scrap!(4),
+            },
+        );
+        assert_eq!(
+            asking,
+            Currencies {
+                keys: 1,
+                weapons: 0,
+            },
+        );
+    }
+    
+    #[test]
+    fn parses_trade_summary_without_labels() {
+        let (offering, asking) = parse_trade_summary("5 ref|1 key, 2 ref").unwrap();
+        
+        assert_eq!(
+            offering,
+            Currencies {
+                keys: 0,
+                weapons: refined!(5),
+            },
+        );
+        assert_eq!(
+            asking,
+            Currencies {
+                keys: 1,
+                weapons: refined!(2),
+            },
+        );
+    }
+    
+    #[test]
+    fn parses_trade_summary_with_delimiter() {
+        let (offering, asking) = parse_trade_summary_with(
+            "offering: 1 key; asking: 60 ref",
+            ';',
+        ).unwrap();
+        
+        assert_eq!(offering.keys, 1);
+        assert_eq!(asking.weapons, refined!(60));
+    }
+    
+    #[test]
+    fn parses_trade_summary_missing_side() {
+        assert!(matches!(
+            parse_trade_summary("Offering: 2 keys, 23.44 ref"),
+            Err(ParseError::NoCurrenciesDetected),
+        ));
+        assert!(parse_trade_summary("Offering: 2 keys | Asking:").is_err());
+    }
+    
+    #[test]
+    fn parses_trade_summary_too_many_sides() {
+        assert!(matches!(
+            parse_trade_summary("2 keys | 1 key | 3 keys"),
+            Err(ParseError::UnexpectedToken { span: Some((16, 23)), .. }),
+        ));
+    }
+    
+    #[test]
+    fn parses_trade_summary_error_span_points_into_summary() {
+        let input = "Offering: 2 keys | Asking: 3 what";
+        let error = parse_trade_summary(input).unwrap_err();
+        let span = error.span().unwrap();
+        
+        assert_eq!(&input[span.0..span.1], "what");
+    }
+    
+    #[test]
+    fn profit_from_flip() {
+        let key_price_weapons = refined!(50);
+        let buy = Currencies {
+            keys: 2,
+            weapons: refined!(10),
+        };
+        let sell = Currencies {
+            keys: 2,
+            weapons: refined!(30),
+        };
+        
+        // 130 ref less a 5% fee of 6.5 ref is 123.5 ref, which is 13.5 ref of profit.
+        assert_eq!(
+            profit(&buy, &sell, 5.0, key_price_weapons),
+            Currencies {
+                keys: 0,
+                weapons: refined!(13) + scrap!(4) + 1,
+            },
+        );
+    }
+    
+    #[test]
+    fn profit_from_loss() {
+        let key_price_weapons = refined!(50);
+        let buy = Currencies {
+            keys: 2,
+            weapons: 0,
+        };
+        let sell = Currencies {
+            keys: 1,
+            weapons: refined!(40),
+        };
+        
+        assert_eq!(
+            profit(&buy, &sell, 10.0, key_price_weapons),
+            Currencies {
+                keys: 0,
+                weapons: -refined!(19),
+            },
+        );
+    }
+}
\ No newline at end of file